@@ -0,0 +1,34 @@
+use advent::common::{read_sections, StrIterator};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{Context, Result};
+
+// The total calories carried by each elf, from largest to smallest.
+fn read_input(path: &str) -> Result<Vec<i64>> {
+    let mut totals: Vec<i64> = Vec::new();
+    for section in read_sections(path)? {
+        let calories: Vec<i64> = section.into_iter().parse_all()?;
+        totals.push(calories.iter().sum());
+    }
+    totals.sort_by(|a, b| b.cmp(a));
+    debug!("{} elves", totals.len());
+    Ok(totals)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i64>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok((*input.first().context("no elves")?).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.iter().take(3).sum::<i64>().into())
+    }
+}
@@ -0,0 +1,107 @@
+use advent::common::read_lines;
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, bail, Result};
+
+fn read_input(path: &str) -> Result<Vec<(char, char)>> {
+    let mut matches = Vec::new();
+    for line in read_lines(path)? {
+        let chars: Vec<char> = line.chars().collect();
+        let [other, ' ', you] = chars[..] else {
+            bail!("invalid line {}: {:?}", line.number, line.text);
+        };
+        matches.push((other, you));
+    }
+    Ok(matches)
+}
+
+fn beats(you: char, other: char) -> Result<u32> {
+    // A: Rock
+    // B: Paper
+    // C: Scissors
+    let score = match you {
+        'A' => match other {
+            'A' => 3,
+            'B' => 0,
+            'C' => 6,
+            _ => bail!("invalid play {:?}", other),
+        },
+        'B' => match other {
+            'A' => 6,
+            'B' => 3,
+            'C' => 0,
+            _ => bail!("invalid play {:?}", other),
+        },
+        'C' => match other {
+            'A' => 0,
+            'B' => 6,
+            'C' => 3,
+            _ => bail!("invalid play {:?}", other),
+        },
+        _ => bail!("invalid play {:?}", you),
+    };
+    Ok(score)
+}
+
+// In part 1, the second column is the shape to play.
+fn play1(you_secret: char, _other: char) -> Result<char> {
+    Ok(match you_secret {
+        'X' => 'A',
+        'Y' => 'B',
+        'Z' => 'C',
+        _ => bail!("bad letter: {:?}", you_secret),
+    })
+}
+
+// In part 2, the second column says whether to lose, draw, or win.
+fn play2(you_secret: char, other: char) -> Result<char> {
+    let bad_letter = || anyhow!("bad letter: {:?}", other);
+    Ok(match you_secret {
+        'X' => match other {
+            'A' => 'C',
+            'B' => 'A',
+            'C' => 'B',
+            _ => return Err(bad_letter()),
+        },
+        'Y' => other,
+        'Z' => match other {
+            'A' => 'B',
+            'B' => 'C',
+            'C' => 'A',
+            _ => return Err(bad_letter()),
+        },
+        _ => bail!("bad letter: {:?}", you_secret),
+    })
+}
+
+fn score(matches: &[(char, char)], xlt: fn(char, char) -> Result<char>) -> Result<u32> {
+    let mut score = 0;
+    for &(other, you_secret) in matches.iter() {
+        let you = xlt(you_secret, other)?;
+        let shape_score = match you {
+            'A' => 1,
+            'B' => 2,
+            'C' => 3,
+            _ => bail!("invalid play {:?}", you),
+        };
+        score += beats(you, other)? + shape_score;
+    }
+    Ok(score)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(char, char)>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(score(input, play1)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(score(input, play2)?.into())
+    }
+}
@@ -0,0 +1,66 @@
+use advent::common::read_lines;
+use advent::solution::{Answer, Solution};
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+
+fn read_input(path: &str) -> Result<Vec<String>> {
+    Ok(read_lines(path)?.map(|line| line.text).collect())
+}
+
+fn score(c: char) -> Result<u32> {
+    Ok(match c {
+        'A'..='Z' => ((c as u32) - ('A' as u32)) + 27,
+        'a'..='z' => ((c as u32) - ('a' as u32)) + 1,
+        _ => bail!("invalid char: {:?}", c),
+    })
+}
+
+fn score_all(items: impl Iterator<Item = char>) -> Result<u32> {
+    items.map(score).sum()
+}
+
+// The items in both compartments of each rucksack.
+fn process1(rucksacks: &[String]) -> Result<u32> {
+    let mut sum = 0;
+    for rucksack in rucksacks {
+        let (part1, part2) = rucksack.split_at(rucksack.len() / 2);
+        let set1: HashSet<char> = part1.chars().collect();
+        let set2: HashSet<char> = part2.chars().collect();
+        sum += score_all(set1.intersection(&set2).copied())?;
+    }
+    Ok(sum)
+}
+
+// The badge carried by each group of three elves.
+fn process2(rucksacks: &[String]) -> Result<u32> {
+    let mut sum = 0;
+    for triad in rucksacks.chunks(3) {
+        let [one, two, three] = triad else {
+            bail!("{} rucksacks don't split into groups of 3", rucksacks.len());
+        };
+        let one: HashSet<char> = one.chars().collect();
+        let two: HashSet<char> = two.chars().collect();
+        let three: HashSet<char> = three.chars().collect();
+        let common: HashSet<char> = one.intersection(&two).copied().collect();
+        sum += score_all(common.intersection(&three).copied())?;
+    }
+    Ok(sum)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(process1(input)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(process2(input)?.into())
+    }
+}
@@ -0,0 +1,47 @@
+use advent::common::{read_lines, Interval, Template};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::Result;
+
+type Pair = (Interval<u32>, Interval<u32>);
+
+fn read_input(path: &str) -> Result<Vec<Pair>> {
+    let template = Template::new("{start1}-{end1},{start2}-{end2}");
+    let mut pairs = Vec::new();
+    for line in read_lines(path)? {
+        let c = template.captures(&line)?;
+        let r1 = Interval::inclusive(c.get("start1")?, c.get("end1")?);
+        let r2 = Interval::inclusive(c.get("start2")?, c.get("end2")?);
+        debug!("{:?}", (r1, r2));
+        pairs.push((r1, r2));
+    }
+    Ok(pairs)
+}
+
+// Whether one of the assignments covers the other.
+fn is_redundant_pair((r1, r2): &Pair) -> bool {
+    let overlap = r1.intersection(r2);
+    overlap == Some(*r1) || overlap == Some(*r2)
+}
+
+fn overlap((r1, r2): &Pair) -> bool {
+    r1.intersection(r2).is_some()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Pair>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.iter().filter(|p| is_redundant_pair(p)).count().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.iter().filter(|p| overlap(p)).count().into())
+    }
+}
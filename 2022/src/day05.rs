@@ -0,0 +1,116 @@
+use advent::common::log::Level;
+use advent::common::{read_lines, Template};
+use advent::solution::{Answer, Solution};
+use advent::{debug, log_enabled};
+use anyhow::{bail, Context, Result};
+
+// The starting stacks aren't in the input file, so they're copied here from the puzzle:
+//                 [J]             [B] [W]
+//                 [T]     [W] [F] [R] [Z]
+//             [Q] [M]     [J] [R] [W] [H]
+//         [F] [L] [P]     [R] [N] [Z] [G]
+//     [F] [M] [S] [Q]     [M] [P] [S] [C]
+//     [L] [V] [R] [V] [W] [P] [C] [P] [J]
+//     [M] [Z] [V] [S] [S] [V] [Q] [H] [M]
+//     [W] [B] [H] [F] [L] [F] [J] [V] [B]
+//      1   2   3   4   5   6   7   8   9
+// Each string is a stack from the bottom up. Stack 0 is unused, so the indices match.
+const STACKS: [&str; 10] = [
+    "", "WMLF", "BZVMF", "HVRSLQ", "FSVQPMTJ", "LSW", "FVPMRJW", "JQCPNRF", "VHPSZWRB", "BMJCGHZW",
+];
+
+#[derive(Debug)]
+pub struct Move {
+    amount: usize,
+    src: usize,
+    dst: usize,
+}
+
+fn read_input(path: &str) -> Result<Vec<Move>> {
+    let template = Template::new("move {amount} from {src} to {dst}");
+    let mut moves = Vec::new();
+    for line in read_lines(path)? {
+        let c = template.captures(&line)?;
+        let m = Move {
+            amount: c.get("amount")?,
+            src: c.get("src")?,
+            dst: c.get("dst")?,
+        };
+        if m.src >= STACKS.len() || m.dst >= STACKS.len() {
+            bail!(
+                "invalid move from {} to {} on line {}",
+                m.src,
+                m.dst,
+                line.number
+            );
+        }
+        moves.push(m);
+    }
+    Ok(moves)
+}
+
+fn render(stacks: &[String]) -> String {
+    let max_depth = stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut s = String::new();
+    for i in 1..=max_depth {
+        for stack in stacks.iter() {
+            if max_depth - i < stack.len() {
+                s.push_str(&format!("[{}] ", stack.as_bytes()[max_depth - i] as char));
+            } else {
+                s.push_str("    ");
+            }
+        }
+        s.push('\n');
+    }
+    for i in 0..stacks.len() {
+        if i == 0 {
+            s.push_str("    ");
+        } else {
+            s.push_str(&format!(" {}  ", i));
+        }
+    }
+    s
+}
+
+// Moves crates one at a time, or all at once if `in_order` is set, and returns the tops.
+fn process(moves: &[Move], in_order: bool) -> Result<String> {
+    let mut stacks: Vec<String> = STACKS.iter().map(|&s| s.to_owned()).collect();
+    for m in moves {
+        if log_enabled!(Level::Debug) {
+            debug!("\n{}", render(&stacks));
+            debug!("{:?}", m);
+        }
+        let stack = &mut stacks[m.src];
+        let split = stack
+            .len()
+            .checked_sub(m.amount)
+            .with_context(|| format!("not enough crates in stack {} for {:?}", m.src, m))?;
+        let mut items = stack.split_off(split);
+        if !in_order {
+            items = items.chars().rev().collect();
+        }
+        stacks[m.dst].push_str(&items);
+    }
+    Ok(stacks
+        .iter()
+        .filter_map(|stack| stack.chars().last())
+        .collect())
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Move>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(process(input, false)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(process(input, true)?.into())
+    }
+}
@@ -0,0 +1,56 @@
+use advent::common::read_lines;
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, bail, Result};
+use std::collections::{HashMap, VecDeque};
+
+fn read_input(path: &str) -> Result<String> {
+    let line = read_lines(path)?
+        .next()
+        .ok_or_else(|| anyhow!("empty input: {:?}", path))?;
+    Ok(line.text)
+}
+
+// Returns how many characters are read before the last `span` are all different.
+fn find_marker(data: &str, span: usize) -> Result<usize> {
+    let mut previous: VecDeque<char> = VecDeque::new();
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for (i, c) in data.chars().enumerate() {
+        // Add the new one.
+        *counts.entry(c).or_insert(0) += 1;
+        previous.push_back(c);
+
+        // Remove the old one.
+        if previous.len() > span {
+            let c2 = previous.pop_front().unwrap();
+            let n = counts.get_mut(&c2).unwrap();
+            if *n == 1 {
+                counts.remove(&c2);
+            } else {
+                *n -= 1;
+            }
+        }
+
+        if previous.len() == span && counts.len() == span {
+            return Ok(i + 1);
+        }
+    }
+    bail!("no marker of length {} found", span)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(find_marker(input, 4)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(find_marker(input, 14)?.into())
+    }
+}
@@ -0,0 +1,127 @@
+use advent::common::{read_lines, Line};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, bail, Context, Result};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+const DISK_SIZE: usize = 70000000;
+const SPACE_NEEDED: usize = 30000000;
+
+type KDirectoryRef = Rc<RefCell<KDirectory>>;
+
+#[derive(Default)]
+struct KDirectory {
+    directories: HashMap<String, KDirectoryRef>,
+    files: HashMap<String, usize>,
+}
+
+fn process_cd(arg: &str, cwd: &mut Vec<KDirectoryRef>) -> Result<()> {
+    if arg == ".." {
+        if cwd.len() == 1 {
+            bail!("cd above root!");
+        }
+        cwd.pop();
+    } else if arg == "/" {
+        cwd.truncate(1);
+    } else {
+        let d = cwd.last().context("root is missing somehow")?.clone();
+        let d2 = d
+            .borrow_mut()
+            .directories
+            .entry(arg.to_owned())
+            .or_default()
+            .clone();
+        cwd.push(d2);
+    }
+    Ok(())
+}
+
+fn process_output(line: &Line, cwd: &[KDirectoryRef]) -> Result<()> {
+    let (size, name) = line
+        .split_once(' ')
+        .ok_or_else(|| anyhow!("invalid output on line {}: {}", line.number, line))?;
+    if size == "dir" {
+        return Ok(());
+    }
+    let size: usize = line.token(size).parse()?;
+    debug!("{}: {}", name, size);
+    cwd.last()
+        .context("root is missing somehow")?
+        .borrow_mut()
+        .files
+        .insert(name.to_owned(), size);
+    Ok(())
+}
+
+// Replays the terminal session to rebuild the directory tree.
+fn process_commands(path: &str) -> Result<KDirectoryRef> {
+    let root: KDirectoryRef = Default::default();
+    let mut cwd = vec![root.clone()];
+    for line in read_lines(path)? {
+        match line.strip_prefix("$ ") {
+            Some(command) => {
+                let result = match command.split_at(command.len().min(2)) {
+                    ("cd", arg) => process_cd(arg.trim(), &mut cwd),
+                    ("ls", _) => Ok(()),
+                    _ => Err(anyhow!("unknown command: {:?}", command)),
+                };
+                result.with_context(|| format!("on line {}", line.number))?;
+            }
+            // Output errors already say where they are.
+            None => process_output(&line, &cwd)?,
+        }
+    }
+    Ok(root)
+}
+
+// Adds the total size of each directory under root to sizes, and returns root's.
+fn compute_sizes(root: &KDirectoryRef, sizes: &mut Vec<usize>) -> usize {
+    let root = root.borrow();
+    let mut size: usize = root.files.values().sum();
+    for d in root.directories.values() {
+        size += compute_sizes(d, sizes);
+    }
+    sizes.push(size);
+    size
+}
+
+// The size of every directory. The last one is the root.
+fn read_input(path: &str) -> Result<Vec<usize>> {
+    let root = process_commands(path)?;
+    let mut sizes = Vec::new();
+    let total = compute_sizes(&root, &mut sizes);
+    debug!("total = {:9}", total);
+    Ok(sizes)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .iter()
+            .filter(|&&size| size <= 100000)
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let total = *input.last().context("no directories")?;
+        let target = (total + SPACE_NEEDED).saturating_sub(DISK_SIZE);
+        debug!("target = {:9}", target);
+        let smallest = input
+            .iter()
+            .filter(|&&size| size >= target)
+            .min()
+            .context("no directory is big enough")?;
+        Ok((*smallest).into())
+    }
+}
@@ -0,0 +1,69 @@
+use advent::common::{read_grid, Array2D};
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, Result};
+
+fn read_input(path: &str) -> Result<Array2D<u32>> {
+    Ok(read_grid(path, |c| {
+        c.to_digit(10)
+            .ok_or_else(|| anyhow!("invalid height: {:?}", c))
+    })?)
+}
+
+// The heights of the trees from (r, c) to each edge, nearest first: left, right, up, down.
+fn lines_of_sight(grid: &Array2D<u32>, r: usize, c: usize) -> [Vec<u32>; 4] {
+    let row = grid.row(r);
+    let column: Vec<u32> = grid.column(c).copied().collect();
+    [
+        row[..c].iter().rev().copied().collect(),
+        row[c + 1..].to_vec(),
+        column[..r].iter().rev().copied().collect(),
+        column[r + 1..].to_vec(),
+    ]
+}
+
+fn is_visible(grid: &Array2D<u32>, r: usize, c: usize) -> bool {
+    let height = grid[(r, c)];
+    lines_of_sight(grid, r, c)
+        .iter()
+        .any(|line| line.iter().all(|&other| other < height))
+}
+
+fn scenic_score(grid: &Array2D<u32>, r: usize, c: usize) -> usize {
+    let height = grid[(r, c)];
+    lines_of_sight(grid, r, c)
+        .iter()
+        .map(
+            |line| match line.iter().position(|&other| other >= height) {
+                Some(i) => i + 1,
+                None => line.len(),
+            },
+        )
+        .product()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Array2D<u32>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .positions()
+            .filter(|&(r, c)| is_visible(input, r, c))
+            .count()
+            .into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .positions()
+            .map(|(r, c)| scenic_score(input, r, c))
+            .max()
+            .unwrap_or(0)
+            .into())
+    }
+}
@@ -0,0 +1,120 @@
+use advent::common::animate::{self, Frame};
+use advent::common::log::Level;
+use advent::common::render::Color;
+use advent::common::{read_lines, Template};
+use advent::solution::{Answer, Solution};
+use advent::{log_enabled, trace};
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+
+pub struct Motion {
+    direction: (i32, i32),
+    amount: i32,
+}
+
+fn read_input(path: &str) -> Result<Vec<Motion>> {
+    let template = Template::new("{direction} {amount}");
+    let mut motions = Vec::new();
+    for line in read_lines(path)? {
+        let c = template.captures(&line)?;
+        let direction = match c.str("direction") {
+            "R" => (1, 0),
+            "L" => (-1, 0),
+            "D" => (0, -1),
+            "U" => (0, 1),
+            other => bail!("invalid direction {:?} on line {}", other, line.number),
+        };
+        motions.push(Motion {
+            direction,
+            amount: c.get("amount")?,
+        });
+    }
+    Ok(motions)
+}
+
+// Draws the rope with the head as H and each knot as its index, with y going up.
+fn render(rope: &[(i32, i32)]) -> Frame {
+    let min_x = rope.iter().map(|k| k.0).min().unwrap_or(0).min(0);
+    let max_x = rope.iter().map(|k| k.0).max().unwrap_or(0).max(5);
+    let min_y = rope.iter().map(|k| k.1).min().unwrap_or(0).min(0);
+    let max_y = rope.iter().map(|k| k.1).max().unwrap_or(0).max(5);
+    let mut s = String::new();
+    for y in (min_y..=max_y).rev() {
+        for x in min_x..=max_x {
+            s.push(match rope.iter().position(|&k| k == (x, y)) {
+                Some(0) => 'H',
+                Some(i) => char::from_digit(i as u32 % 10, 10).unwrap(),
+                None => '.',
+            });
+        }
+        s.push('\n');
+    }
+    let head = rope[0];
+    Frame::new(s).focus((max_y - head.1) as usize, (head.0 - min_x) as usize)
+}
+
+// Every single step the head takes.
+fn steps(motions: &[Motion]) -> impl Iterator<Item = (i32, i32)> + '_ {
+    motions
+        .iter()
+        .flat_map(|m| std::iter::repeat_n(m.direction, m.amount as usize))
+}
+
+// Moves the head one step, and the rest of the knots after it.
+fn step(rope: &mut [(i32, i32)], direction: (i32, i32)) {
+    rope[0].0 += direction.0;
+    rope[0].1 += direction.1;
+    for i in 1..rope.len() {
+        let dx = rope[i - 1].0 - rope[i].0;
+        let dy = rope[i - 1].1 - rope[i].1;
+        // A knot that's no longer touching the one ahead moves one step toward it,
+        // diagonally if they aren't in the same row or column.
+        if dx.abs() > 1 || dy.abs() > 1 {
+            rope[i].0 += dx.signum();
+            rope[i].1 += dy.signum();
+        }
+    }
+}
+
+// Moves the head of a rope with `len` knots and counts the positions its tail visits.
+fn simulate(motions: &[Motion], len: usize) -> Result<usize> {
+    let mut rope = vec![(0, 0); len];
+    let mut visited = HashSet::new();
+    visited.insert(rope[len - 1]);
+    let mut steps = steps(motions);
+    animate::simulate(
+        &mut rope,
+        &[('H', Color::rgb(255, 80, 80))],
+        |rope| {
+            let Some(direction) = steps.next() else {
+                return false;
+            };
+            step(rope, direction);
+            visited.insert(rope[len - 1]);
+            if log_enabled!(Level::Trace) {
+                trace!("\n{}", render(rope).text);
+            }
+            true
+        },
+        |rope| render(rope),
+    )?;
+    Ok(visited.len())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Motion>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(simulate(input, 2)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(simulate(input, 10)?.into())
+    }
+}
@@ -0,0 +1,134 @@
+use advent::common::read_lines;
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{bail, Context, Result};
+
+pub enum Instruction {
+    Noop,
+    Addx(i64),
+}
+
+fn read_input(path: &str) -> Result<Vec<Instruction>> {
+    let mut instructions = Vec::new();
+    for line in read_lines(path)? {
+        instructions.push(if line.text == "noop" {
+            Instruction::Noop
+        } else if let Some(n) = line.strip_prefix("addx ") {
+            Instruction::Addx(
+                n.parse()
+                    .with_context(|| format!("invalid number on line {}: {}", line.number, n))?,
+            )
+        } else {
+            bail!("invalid line {}: {}", line.number, line.text);
+        });
+    }
+    Ok(instructions)
+}
+
+// The value of the register during each cycle, starting with cycle 1.
+fn register_values(instructions: &[Instruction]) -> Vec<i64> {
+    let mut register = 1;
+    let mut values = Vec::new();
+    for instruction in instructions {
+        match instruction {
+            Instruction::Noop => values.push(register),
+            Instruction::Addx(n) => {
+                values.push(register);
+                values.push(register);
+                register += n;
+            }
+        }
+    }
+    values.push(register);
+    values
+}
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+// Draws a pixel wherever the 3-pixel-wide sprite overlaps the beam.
+fn render(values: &[i64]) -> Vec<String> {
+    values
+        .chunks(SCREEN_WIDTH)
+        .take(SCREEN_HEIGHT)
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(x, &register)| {
+                    if (x as i64 - register).abs() <= 1 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// The letters of the puzzle font, which are 4 pixels wide and 6 tall.
+const LETTERS: [(char, [&str; SCREEN_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+    (' ', ["....", "....", "....", "....", "....", "...."]),
+];
+
+// Reads the letters drawn on the screen. Each one is followed by a blank column.
+fn read_letters(screen: &[String]) -> Result<String> {
+    let mut letters = String::new();
+    for start in (0..SCREEN_WIDTH).step_by(5) {
+        let glyph: Vec<&str> = screen.iter().map(|row| &row[start..start + 4]).collect();
+        let Some((letter, _)) = LETTERS.iter().find(|(_, pixels)| pixels[..] == glyph[..]) else {
+            bail!("unknown letter at column {}:\n{}", start, screen.join("\n"));
+        };
+        letters.push(*letter);
+    }
+    Ok(letters.trim().to_owned())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let values = register_values(input);
+        let sum: i64 = [20, 60, 100, 140, 180, 220]
+            .iter()
+            .filter_map(|&cycle| values.get(cycle - 1).map(|&x| cycle as i64 * x))
+            .sum();
+        Ok(sum.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let values = register_values(input);
+        if values.len() < SCREEN_WIDTH * SCREEN_HEIGHT {
+            bail!(
+                "only {} cycles, which doesn't fill the screen",
+                values.len()
+            );
+        }
+        let screen = render(&values);
+        debug!("\n{}", screen.join("\n"));
+        Ok(read_letters(&screen)?.into())
+    }
+}
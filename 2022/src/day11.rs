@@ -0,0 +1,146 @@
+use advent::common::{read_sections, Line, Template};
+use advent::solution::{Answer, Solution};
+use advent::{debug, trace};
+use anyhow::{bail, Result};
+use std::collections::VecDeque;
+
+type Worry = i64;
+
+#[derive(Debug, Clone, Copy)]
+enum Operand {
+    Old,
+    Value(Worry),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Add(Operand),
+    Multiply(Operand),
+}
+
+impl Operation {
+    fn apply(&self, old: Worry) -> Worry {
+        let value = |operand| match operand {
+            Operand::Old => old,
+            Operand::Value(n) => n,
+        };
+        match *self {
+            Operation::Add(operand) => old + value(operand),
+            Operation::Multiply(operand) => old * value(operand),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<Worry>,
+    op: Operation,
+    test: Worry,
+    yes: usize,
+    no: usize,
+}
+
+struct Templates {
+    items: Template,
+    op: Template,
+    test: Template,
+    yes: Template,
+    no: Template,
+}
+
+fn read_monkey(section: Vec<Line>, templates: &Templates) -> Result<Monkey> {
+    let lines: Vec<Line> = section.into_iter().map(Line::trimmed).collect();
+    let [_, items, op, test, yes, no] = &lines[..] else {
+        bail!("expected 6 lines for a monkey, got {}", lines.len());
+    };
+    let op = templates.op.captures(op)?;
+    let operand = match op.str("operand") {
+        "old" => Operand::Old,
+        _ => Operand::Value(op.get("operand")?),
+    };
+    let op = match op.str("op") {
+        "+" => Operation::Add(operand),
+        "*" => Operation::Multiply(operand),
+        other => bail!("invalid operation {:?} on line {}", other, lines[2].number),
+    };
+    Ok(Monkey {
+        items: templates.items.captures(items)?.list("items")?.into(),
+        op,
+        test: templates.test.captures(test)?.get("test")?,
+        yes: templates.yes.captures(yes)?.get("monkey")?,
+        no: templates.no.captures(no)?.get("monkey")?,
+    })
+}
+
+fn read_input(path: &str) -> Result<Vec<Monkey>> {
+    let templates = Templates {
+        items: Template::new("Starting items: {items:, }"),
+        op: Template::new("Operation: new = old {op} {operand}"),
+        test: Template::new("Test: divisible by {test}"),
+        yes: Template::new("If true: throw to monkey {monkey}"),
+        no: Template::new("If false: throw to monkey {monkey}"),
+    };
+    let mut monkeys = Vec::new();
+    for section in read_sections(path)? {
+        monkeys.push(read_monkey(section, &templates)?);
+    }
+    for monkey in monkeys.iter() {
+        if monkey.yes >= monkeys.len() || monkey.no >= monkeys.len() {
+            bail!("monkey {:?} throws to a monkey that doesn't exist", monkey);
+        }
+    }
+    Ok(monkeys)
+}
+
+// Returns the product of the two largest numbers of items inspected by a monkey.
+// Without relief, worry levels are kept small by reducing them modulo every test.
+fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: bool) -> i64 {
+    let mut monkeys = monkeys.to_vec();
+    let modulus: Worry = monkeys.iter().map(|m| m.test).product();
+    let mut inspected = vec![0; monkeys.len()];
+    for round in 1..=rounds {
+        for i in 0..monkeys.len() {
+            while let Some(item) = monkeys[i].items.pop_front() {
+                inspected[i] += 1;
+                let monkey = &monkeys[i];
+                let worry = monkey.op.apply(item);
+                let worry = if relief { worry / 3 } else { worry % modulus };
+                let new_monkey = if worry % monkey.test == 0 {
+                    monkey.yes
+                } else {
+                    monkey.no
+                };
+                trace!(
+                    "monkey {} inspects {}, and throws {} to monkey {}",
+                    i,
+                    item,
+                    worry,
+                    new_monkey
+                );
+                monkeys[new_monkey].items.push_back(worry);
+            }
+        }
+        trace!("after round {}: {:?}", round, monkeys);
+    }
+    debug!("inspected: {:?}", inspected);
+    inspected.sort();
+    inspected.iter().rev().take(2).product()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(monkey_business(input, 20, true).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(monkey_business(input, 10000, false).into())
+    }
+}
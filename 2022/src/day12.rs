@@ -0,0 +1,80 @@
+use advent::common::{bfs, read_grid_with_markers, Array2D, Graph, Topology};
+use advent::debug;
+use advent::solution::{record_counter, Answer, Solution};
+use anyhow::{anyhow, Context, Result};
+
+pub struct Map {
+    start: (usize, usize),
+    end: (usize, usize),
+    elevation: Array2D<u32>,
+}
+
+fn read_input(path: &str) -> Result<Map> {
+    let (elevation, markers) =
+        read_grid_with_markers(path, &["start", "end"], |c, (r, col), markers| match c {
+            'S' => {
+                markers.mark("start", r, col);
+                Ok(0)
+            }
+            'E' => {
+                markers.mark("end", r, col);
+                Ok(25)
+            }
+            'a'..='z' => Ok(c as u32 - 'a' as u32),
+            _ => Err(anyhow!("invalid elevation: {:?}", c)),
+        })?;
+    debug!("start: {:?}, end: {:?}", markers["start"], markers["end"]);
+    Ok(Map {
+        start: markers["start"],
+        end: markers["end"],
+        elevation,
+    })
+}
+
+impl Graph for Map {
+    type Node = (usize, usize);
+
+    // Each step can climb at most one higher, but can drop any amount.
+    fn successors(&self, &(r, c): &Self::Node) -> Vec<(Self::Node, i64)> {
+        let height = self.elevation[(r, c)];
+        self.elevation
+            .neighbors4(r, c, Topology::Bounded)
+            .filter(|&(_, _, &next)| next <= height + 1)
+            .map(|(r2, c2, _)| ((r2, c2), 1))
+            .collect()
+    }
+
+    fn is_goal(&self, node: &Self::Node) -> bool {
+        *node == self.end
+    }
+}
+
+// The fewest steps from any of the starts to the end.
+fn climb(map: &Map, starts: impl IntoIterator<Item = (usize, usize)>) -> Result<i64> {
+    let result = bfs(map, starts).context("no path to the end")?;
+    record_counter("nodes_expanded", result.stats.expanded as u64);
+    Ok(result.cost)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Map;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(climb(input, [input.start])?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let lowest = input
+            .elevation
+            .enumerate()
+            .filter(|&(_, _, &height)| height == 0)
+            .map(|(r, c, _)| (r, c));
+        Ok(climb(input, lowest)?.into())
+    }
+}
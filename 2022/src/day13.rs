@@ -0,0 +1,181 @@
+use advent::common::read_lines;
+use advent::solution::{Answer, Solution};
+use advent::trace;
+use anyhow::{anyhow, bail, Result};
+use itertools::{EitherOrBoth::*, Itertools};
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::iter::Peekable;
+use std::str::Chars;
+
+enum Token {
+    EndOfInput,
+    OpenBracket,
+    CloseBracket,
+    Comma,
+    Number(u32),
+}
+
+fn read_number(chars: &mut Peekable<Chars>) -> Result<Token> {
+    if chars.peek().is_none() {
+        bail!("expected digit, got end of string");
+    }
+    let mut num = 0;
+    loop {
+        match chars.peek() {
+            None | Some(']' | ',') => return Ok(Token::Number(num)),
+            Some(c) => {
+                let digit = c
+                    .to_digit(10)
+                    .ok_or_else(|| anyhow!("expected digit, got {}", c))?;
+                num = (num * 10) + digit;
+            }
+        }
+        chars.next();
+    }
+}
+
+fn read_token(chars: &mut Peekable<Chars>) -> Result<Token> {
+    let token = match chars.peek() {
+        None => return Ok(Token::EndOfInput),
+        Some('[') => Token::OpenBracket,
+        Some(']') => Token::CloseBracket,
+        Some(',') => Token::Comma,
+        _ => return read_number(chars),
+    };
+    chars.next();
+    Ok(token)
+}
+
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Number(u32),
+    List(Vec<Packet>),
+}
+
+fn read_list(chars: &mut Peekable<Chars>) -> Result<Packet> {
+    let mut v = Vec::new();
+    loop {
+        match read_token(chars)? {
+            Token::CloseBracket => return Ok(Packet::List(v)),
+            Token::Number(n) => v.push(Packet::Number(n)),
+            Token::OpenBracket => v.push(read_list(chars)?),
+            Token::Comma => {}
+            Token::EndOfInput => bail!("expected list, got end of input"),
+        }
+    }
+}
+
+fn read_packet(s: &str) -> Result<Packet> {
+    let mut chars = s.chars().peekable();
+    match read_token(&mut chars)? {
+        Token::OpenBracket => read_list(&mut chars),
+        _ => bail!("expected ["),
+    }
+}
+
+fn compare(left: &Packet, right: &Packet) -> Ordering {
+    match (left, right) {
+        (Packet::Number(l), Packet::Number(r)) => l.cmp(r),
+        (Packet::Number(l), Packet::List(_)) => {
+            compare(&Packet::List(vec![Packet::Number(*l)]), right)
+        }
+        (Packet::List(_), Packet::Number(r)) => {
+            compare(left, &Packet::List(vec![Packet::Number(*r)]))
+        }
+        (Packet::List(l), Packet::List(r)) => {
+            for pair in l.iter().zip_longest(r.iter()) {
+                match pair {
+                    Both(l, r) => match compare(l, r) {
+                        Ordering::Equal => {}
+                        ordering => return ordering,
+                    },
+                    Left(_) => return Ordering::Greater,
+                    Right(_) => return Ordering::Less,
+                }
+            }
+            Ordering::Equal
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(self, other)
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        compare(self, other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Number(n) => write!(f, "{}", n),
+            Packet::List(v) => write!(f, "[{}]", v.iter().join(",")),
+        }
+    }
+}
+
+fn read_input(path: &str) -> Result<Vec<Packet>> {
+    let mut packets = Vec::new();
+    for line in read_lines(path)? {
+        packets.push(line.token(&line).parse_with(read_packet)?);
+    }
+    Ok(packets)
+}
+
+// A divider packet added in part 2, such as [[2]].
+fn divider(n: u32) -> Packet {
+    Packet::List(vec![Packet::List(vec![Packet::Number(n)])])
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Packet>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let mut sum = 0;
+        for (i, pair) in input.chunks(2).enumerate() {
+            let [left, right] = pair else {
+                bail!("the last packet has no pair");
+            };
+            let ordering = compare(left, right);
+            trace!("pair {}: {} vs {} is {:?}", i + 1, left, right, ordering);
+            if ordering == Ordering::Less {
+                sum += i + 1;
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let mut packets = input.clone();
+        packets.push(divider(2));
+        packets.push(divider(6));
+        packets.sort();
+        let mut ans = 1;
+        for (i, packet) in packets.iter().enumerate() {
+            if *packet == divider(2) || *packet == divider(6) {
+                ans *= i + 1;
+            }
+        }
+        Ok(ans.into())
+    }
+}
@@ -0,0 +1,159 @@
+use advent::common::animate::{self, Frame};
+use advent::common::log::Level;
+use advent::common::render::Color;
+use advent::common::{read_lines, split_on};
+use advent::solution::{Answer, Solution};
+use advent::{debug, log_enabled};
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+#[derive(Clone, Copy)]
+enum Tile {
+    Sand,
+    Rock,
+}
+
+#[derive(Clone)]
+pub struct Map {
+    min_x: usize,
+    min_y: usize,
+    max_x: usize,
+    max_y: usize,
+    tiles: HashMap<(usize, usize), Tile>,
+}
+
+impl Map {
+    fn is_empty(&self, x: usize, y: usize) -> bool {
+        !self.tiles.contains_key(&(x, y))
+    }
+
+    fn render(&self) -> String {
+        let mut s = String::new();
+        for y in self.min_y..=self.max_y {
+            for x in self.min_x..=self.max_x {
+                s.push(match self.tiles.get(&(x, y)) {
+                    None => '.',
+                    Some(Tile::Sand) => 'o',
+                    Some(Tile::Rock) => '#',
+                });
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+fn read_input(path: &str) -> Result<Map> {
+    let mut paths = Vec::new();
+    for line in read_lines(path)? {
+        let mut path = Vec::new();
+        for pair in line.split(" -> ") {
+            let (x, y) = split_on(pair, ',')
+                .ok_or_else(|| anyhow!("expected comma in {:?} on line {}", pair, line.number))?;
+            let x: usize = line.token(x).parse()?;
+            let y: usize = line.token(y).parse()?;
+            path.push((x, y));
+        }
+        paths.push(path);
+    }
+
+    let points = || paths.iter().flatten();
+    let mut m = Map {
+        min_x: points().map(|p| p.0).min().unwrap_or(500),
+        min_y: points().map(|p| p.1).min().unwrap_or(0),
+        max_x: points().map(|p| p.0).max().unwrap_or(500),
+        max_y: points().map(|p| p.1).max().unwrap_or(0),
+        tiles: HashMap::new(),
+    };
+    for path in paths.iter() {
+        for segment in path.windows(2) {
+            let (a, b) = (segment[0], segment[1]);
+            for y in a.1.min(b.1)..=a.1.max(b.1) {
+                for x in a.0.min(b.0)..=a.0.max(b.0) {
+                    m.tiles.insert((x, y), Tile::Rock);
+                }
+            }
+        }
+    }
+    Ok(m)
+}
+
+// Drops one unit of sand from (500, 0) and returns where it came to rest, if it did.
+// With a floor, sand rests just above it instead of falling into the abyss.
+fn drop_sand(m: &mut Map, floor: bool) -> Option<(usize, usize)> {
+    let (mut x, mut y) = (500, 0);
+    if !m.is_empty(x, y) {
+        return None;
+    }
+    loop {
+        if y > m.max_y {
+            if !floor {
+                return None;
+            }
+            break;
+        }
+        if m.is_empty(x, y + 1) {
+            y += 1;
+        } else if m.is_empty(x - 1, y + 1) {
+            x -= 1;
+            y += 1;
+        } else if m.is_empty(x + 1, y + 1) {
+            x += 1;
+            y += 1;
+        } else {
+            break;
+        }
+    }
+    m.tiles.insert((x, y), Tile::Sand);
+    Some((x, y))
+}
+
+const TILE_COLORS: [(char, Color); 2] = [
+    ('o', Color::rgb(230, 200, 120)),
+    ('#', Color::rgb(140, 140, 150)),
+];
+
+fn fill(map: &Map, floor: bool) -> Result<usize> {
+    // The map, and where the last unit of sand landed.
+    let mut state = (map.clone(), None);
+    let count = animate::simulate(
+        &mut state,
+        &TILE_COLORS,
+        |(m, last)| {
+            *last = drop_sand(m, floor);
+            last.is_some()
+        },
+        |(m, last)| {
+            let frame = Frame::new(m.render());
+            match *last {
+                // The sand can land outside the rocks' bounds once there's a floor.
+                Some((x, y)) if x >= m.min_x && y >= m.min_y => {
+                    frame.focus(y - m.min_y, x - m.min_x)
+                }
+                _ => frame,
+            }
+        },
+    )?;
+    if log_enabled!(Level::Debug) {
+        debug!("\n{}", state.0.render());
+    }
+    Ok(count)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Map;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(fill(input, false)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(fill(input, true)?.into())
+    }
+}
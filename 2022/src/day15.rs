@@ -0,0 +1,90 @@
+use advent::common::{read_lines, InputSpec, Interval, IntervalSet, Template};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+
+// The row to count in part 1, and the size of the search area in part 2, unless the input spec
+// says otherwise.
+const ROW: i64 = 2000000;
+const MAX: i64 = 4000000;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Entry {
+    sensor: (i64, i64),
+    beacon: (i64, i64),
+}
+
+pub struct Scan {
+    entries: Vec<Entry>,
+    row: i64,
+    max: i64,
+}
+
+impl Entry {
+    fn dist(&self) -> i64 {
+        (self.sensor.0 - self.beacon.0).abs() + (self.sensor.1 - self.beacon.1).abs()
+    }
+
+    // The x positions in row y that are within range of this sensor.
+    fn range_for_y(&self, y: i64) -> Interval<i64> {
+        let remaining = self.dist() - (self.sensor.1 - y).abs();
+        Interval::inclusive(self.sensor.0 - remaining, self.sensor.0 + remaining)
+    }
+}
+
+// Reads the sensors from an input spec such as "sample.txt?row=10&max=20".
+fn read_input(spec: &str) -> Result<Scan> {
+    let spec = InputSpec::parse(spec)?;
+    let template = Template::new("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}");
+    let mut entries = Vec::new();
+    for line in read_lines(spec.path)? {
+        let c = template.captures(&line)?;
+        let entry = Entry {
+            sensor: (c.get("sx")?, c.get("sy")?),
+            beacon: (c.get("bx")?, c.get("by")?),
+        };
+        debug!("{:?}", entry);
+        entries.push(entry);
+    }
+    Ok(Scan {
+        entries,
+        row: spec.param("row", ROW)?,
+        max: spec.param("max", MAX)?,
+    })
+}
+
+fn covered(entries: &[Entry], y: i64) -> IntervalSet<i64> {
+    entries.iter().map(|entry| entry.range_for_y(y)).collect()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Scan;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let beacons: HashSet<_> = input
+            .entries
+            .iter()
+            .filter(|entry| entry.beacon.1 == input.row)
+            .map(|entry| entry.beacon.0)
+            .collect();
+        Ok((covered(&input.entries, input.row).len() - beacons.len() as i64).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let area = IntervalSet::from(Interval::inclusive(0, input.max));
+        for y in 0..=input.max {
+            if let Some(x) = area.difference(&covered(&input.entries, y)).min() {
+                debug!("beacon = ({}, {})", x, y);
+                return Ok((x * 4000000 + y).into());
+            }
+        }
+        Err(anyhow!("no position found for the beacon"))
+    }
+}
@@ -0,0 +1,380 @@
+use advent::checkpoint;
+use advent::common::{bfs, read_lines, Graph, Template};
+use advent::solution::{record_counter, Answer, Solution};
+use advent::{debug, trace};
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+use priority_queue::PriorityQueue;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+struct Valve {
+    name: String,
+    rate: i64,
+    tunnels: Vec<String>,
+}
+
+fn read_valves(path: &str) -> Result<Vec<Valve>> {
+    let template =
+        Template::new("Valve {name} has flow rate={rate}; tunnel{_} lead{_} to valve{_} {list:, }");
+    let mut valves = Vec::new();
+    for line in read_lines(path)? {
+        let c = template.captures(&line)?;
+        valves.push(Valve {
+            name: c.str("name").to_owned(),
+            rate: c.get("rate")?,
+            tunnels: c.list("list")?,
+        });
+    }
+    Ok(valves)
+}
+
+// Walks the tunnels one minute at a time, looking for a particular valve.
+struct Tunnels<'a> {
+    valves: &'a HashMap<&'a str, &'a Valve>,
+    goal: &'a str,
+}
+
+impl<'a> Graph for Tunnels<'a> {
+    type Node = &'a str;
+
+    fn successors(&self, node: &Self::Node) -> Vec<(Self::Node, i64)> {
+        self.valves[node]
+            .tunnels
+            .iter()
+            .filter_map(|t| self.valves.get_key_value(t.as_str()))
+            .map(|(name, _)| (*name, 1))
+            .collect()
+    }
+
+    fn is_goal(&self, node: &Self::Node) -> bool {
+        *node == self.goal
+    }
+}
+
+// Only the valves with a positive flow rate are worth walking to. Index 0 is the start, AA,
+// and the rest are sorted by name.
+pub struct Network {
+    names: Vec<String>,
+    rates: Vec<i64>,
+    dist: Vec<Vec<i64>>,
+}
+
+fn read_input(path: &str) -> Result<Network> {
+    let valves = read_valves(path)?;
+    let by_name: HashMap<&str, &Valve> = valves.iter().map(|v| (v.name.as_str(), v)).collect();
+    if !by_name.contains_key("AA") {
+        bail!("there is no valve AA");
+    }
+    let mut useful = vec!["AA"];
+    useful.extend(
+        valves
+            .iter()
+            .filter(|v| v.rate > 0 && v.name != "AA")
+            .map(|v| v.name.as_str())
+            .sorted(),
+    );
+
+    let mut dist = Vec::new();
+    for start in useful.iter() {
+        let mut row = Vec::new();
+        for goal in useful.iter() {
+            let tunnels = Tunnels {
+                valves: &by_name,
+                goal,
+            };
+            let result = bfs(&tunnels, [*start])
+                .ok_or_else(|| anyhow!("no path from {} to {}", start, goal))?;
+            row.push(result.cost);
+        }
+        dist.push(row);
+    }
+    debug!("distances between {:?}: {:?}", useful, dist);
+
+    let rates = useful.iter().map(|name| by_name[name].rate).collect();
+    let names = useful.iter().map(|name| name.to_string()).collect();
+    Ok(Network { names, rates, dist })
+}
+
+// A route through the network, which opens each valve it visits after the start.
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct ScoredPath {
+    path: Vec<usize>,
+    time: i64,
+    flow: i64,
+    total: i64,
+}
+
+impl ScoredPath {
+    fn start() -> Self {
+        ScoredPath {
+            path: vec![0],
+            time: 0,
+            flow: 0,
+            total: 0,
+        }
+    }
+
+    // The pressure released by `max_time`, if nothing else gets opened.
+    fn score(&self, max_time: i64) -> i64 {
+        self.total + self.flow * (max_time - self.time)
+    }
+
+    // Walks to `next` and opens it, unless it's already open or there's no time left.
+    fn extend(
+        &self,
+        network: &Network,
+        next: usize,
+        max_time: i64,
+        seen: &[usize],
+    ) -> Option<ScoredPath> {
+        if network.rates[next] == 0 || seen.contains(&next) {
+            return None;
+        }
+        let current = *self.path.last().expect("paths start at AA");
+        let cost = network.dist[current][next] + 1;
+        let time = self.time + cost;
+        if time >= max_time {
+            return None;
+        }
+        let mut path = self.path.clone();
+        path.push(next);
+        Some(ScoredPath {
+            path,
+            time,
+            flow: self.flow + network.rates[next],
+            total: self.total + cost * self.flow,
+        })
+    }
+
+    fn render(&self, network: &Network) -> String {
+        let route = self.path.iter().map(|&i| &network.names[i]).join(" -> ");
+        format!(
+            "{} [time={}, flow={}, total={}]",
+            route, self.time, self.flow, self.total
+        )
+    }
+}
+
+// How far a search has gotten, so it can be saved to a checkpoint.
+// The maps are kept as lists, since their keys aren't strings.
+#[derive(Serialize, Deserialize)]
+struct Progress<C, K> {
+    candidates: Vec<(C, i64)>,
+    best_scores: Vec<(K, i64)>,
+    best: i64,
+    total: u64,
+}
+
+impl<C: Clone + Hash + Eq, K: Clone + Hash + Eq> Progress<C, K> {
+    fn new(start: C) -> Self {
+        Progress {
+            candidates: vec![(start, 0)],
+            best_scores: Vec::new(),
+            best: 0,
+            total: 1,
+        }
+    }
+
+    fn save(
+        candidates: &PriorityQueue<C, i64>,
+        best_scores: &HashMap<K, i64>,
+        best: i64,
+        total: u64,
+    ) -> Self {
+        Progress {
+            candidates: candidates.iter().map(|(c, &p)| (c.clone(), p)).collect(),
+            best_scores: best_scores.iter().map(|(k, &v)| (k.clone(), v)).collect(),
+            best,
+            total,
+        }
+    }
+}
+
+// Searches the best-scoring routes first, skipping any that reach a valve at a time
+// when another route had already released more by then.
+fn search1(network: &Network, max_time: i64) -> Result<i64> {
+    #[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    struct Candidate {
+        path: ScoredPath,
+        seen: Vec<usize>,
+    }
+
+    let progress = checkpoint::resume("candidates")?.unwrap_or_else(|| {
+        Progress::new(Candidate {
+            path: ScoredPath::start(),
+            seen: Vec::new(),
+        })
+    });
+    let mut candidates: PriorityQueue<Candidate, i64> = progress.candidates.into_iter().collect();
+    let mut best_scores: HashMap<(usize, i64), i64> = progress.best_scores.into_iter().collect();
+    let mut best = progress.best;
+    let mut total = progress.total;
+    loop {
+        checkpoint::check("candidates", || {
+            Progress::save(&candidates, &best_scores, best, total)
+        })?;
+        let Some((candidate, _)) = candidates.pop() else {
+            break;
+        };
+        trace!(
+            "{} {} considering {}",
+            total,
+            candidates.len(),
+            candidate.path.render(network)
+        );
+
+        for next in 1..network.rates.len() {
+            let Some(path) = candidate
+                .path
+                .extend(network, next, max_time, &candidate.seen)
+            else {
+                continue;
+            };
+            let score = path.score(max_time);
+            best = best.max(score);
+            total += 1;
+
+            let is_best = best_scores
+                .get(&(next, path.time))
+                .is_none_or(|&best_score| score >= best_score);
+            if is_best {
+                for t in path.time..max_time {
+                    best_scores.insert((next, t), score);
+                }
+                let mut seen = candidate.seen.clone();
+                seen.push(next);
+                candidates.push(Candidate { path, seen }, score);
+            }
+        }
+    }
+    record_counter("candidates", total);
+    Ok(best)
+}
+
+// Like search1, but you and the elephant each have a route.
+// Swapping the two routes gives the same score, so the elephant's route is kept
+// at least as big as yours, comparing valve by valve.
+fn search2(network: &Network, max_time: i64) -> Result<i64> {
+    #[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    struct Candidate {
+        human: ScoredPath,
+        elephant: ScoredPath,
+        seen: Vec<usize>,
+    }
+
+    let progress = checkpoint::resume("candidates")?.unwrap_or_else(|| {
+        Progress::new(Candidate {
+            human: ScoredPath::start(),
+            elephant: ScoredPath::start(),
+            seen: Vec::new(),
+        })
+    });
+    let mut candidates: PriorityQueue<Candidate, i64> = progress.candidates.into_iter().collect();
+    let mut best_scores: HashMap<(usize, usize, i64, i64), i64> =
+        progress.best_scores.into_iter().collect();
+    let mut best = progress.best;
+    let mut total = progress.total;
+    loop {
+        checkpoint::check("candidates", || {
+            Progress::save(&candidates, &best_scores, best, total)
+        })?;
+        let Some((candidate, _)) = candidates.pop() else {
+            break;
+        };
+        trace!(
+            "{} {} hum {}",
+            total,
+            candidates.len(),
+            candidate.human.render(network)
+        );
+        trace!(
+            "{} {} ele {}",
+            total,
+            candidates.len(),
+            candidate.elephant.render(network)
+        );
+
+        for next in 1..network.rates.len() {
+            let mut seen = candidate.seen.clone();
+            seen.push(next);
+            let mut new_candidates = Vec::new();
+            if let Some(human) = candidate
+                .human
+                .extend(network, next, max_time, &candidate.seen)
+            {
+                if candidate.elephant.path >= human.path {
+                    new_candidates.push(Candidate {
+                        human,
+                        elephant: candidate.elephant.clone(),
+                        seen: seen.clone(),
+                    });
+                }
+            }
+            if let Some(elephant) =
+                candidate
+                    .elephant
+                    .extend(network, next, max_time, &candidate.seen)
+            {
+                if elephant.path >= candidate.human.path {
+                    new_candidates.push(Candidate {
+                        human: candidate.human.clone(),
+                        elephant,
+                        seen,
+                    });
+                }
+            }
+
+            for new_candidate in new_candidates {
+                total += 1;
+                let score =
+                    new_candidate.human.score(max_time) + new_candidate.elephant.score(max_time);
+                best = best.max(score);
+
+                let human_at = *new_candidate.human.path.last().expect("paths start at AA");
+                let elephant_at = *new_candidate
+                    .elephant
+                    .path
+                    .last()
+                    .expect("paths start at AA");
+                let mut was_best = false;
+                for t_human in new_candidate.human.time..max_time {
+                    for t_elephant in new_candidate.elephant.time..max_time {
+                        let pos = (human_at, elephant_at, t_human, t_elephant);
+                        let is_best = best_scores
+                            .get(&pos)
+                            .is_none_or(|&best_score| score >= best_score);
+                        if is_best {
+                            was_best = true;
+                            best_scores.insert(pos, score);
+                        }
+                    }
+                }
+                if was_best {
+                    candidates.push(new_candidate, score);
+                }
+            }
+        }
+    }
+    record_counter("candidates", total);
+    Ok(best)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Network;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(search1(input, 30)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(search2(input, 26)?.into())
+    }
+}
@@ -0,0 +1,200 @@
+use advent::common::animate::{self, Frame};
+use advent::common::log::Level;
+use advent::common::render::Color;
+use advent::common::{fast_forward_by_key, read_lines, CycleStrategy};
+use advent::solution::{record_counter, Answer, Solution};
+use advent::{debug, log_enabled, trace};
+use anyhow::{bail, Context, Result};
+
+// Each piece is a bitmask per row, from the bottom up, already 2 columns from the left wall.
+type PieceMask = [u8; 4];
+const HLINE_MASK: PieceMask = [0b0011110, 0, 0, 0];
+const PLUS_MASK: PieceMask = [0b0001000, 0b0011100, 0b0001000, 0];
+const ELBOW_MASK: PieceMask = [0b0011100, 0b0000100, 0b0000100, 0];
+const VLINE_MASK: PieceMask = [0b0010000, 0b0010000, 0b0010000, 0b0010000];
+const SQUARE_MASK: PieceMask = [0b0011000, 0b0011000, 0, 0];
+const PIECES: [PieceMask; 5] = [HLINE_MASK, PLUS_MASK, ELBOW_MASK, VLINE_MASK, SQUARE_MASK];
+
+// How many rows from the top to keep. Pieces can't fall any deeper than this in practice,
+// and it makes the board small enough to notice when the states start repeating.
+const MAX_ROWS: usize = 64;
+
+// How many pieces to animate before skipping ahead. The rest only repeat the pattern.
+const ANIMATED_PIECES: u64 = 500;
+
+#[derive(Clone)]
+struct Board {
+    // Rows of the board, with the bottom being the floor.
+    // Each row is a bitmask of whether that column is solid.
+    rows: Vec<u8>,
+
+    // The height of the bottom of the board.
+    floor: u64,
+
+    // Which piece and which jet of wind come next.
+    piece_i: usize,
+    wind_i: usize,
+}
+
+impl Board {
+    fn height(&self) -> u64 {
+        self.floor + self.rows.len() as u64
+    }
+
+    fn row(&self, y: usize) -> u8 {
+        self.rows.get(y).copied().unwrap_or(0)
+    }
+
+    fn fits(&self, piece: &PieceMask, y: usize) -> bool {
+        (0..4).all(|i| self.row(y + i) & piece[i] == 0)
+    }
+
+    fn render(&self) -> String {
+        let mut s = String::new();
+        for (i, row) in self.rows.iter().enumerate().rev() {
+            s.push_str(&format!("{:4} |", i as u64 + self.floor));
+            for col in (0..7).rev() {
+                s.push(if row & (1 << col) != 0 { '#' } else { '.' });
+            }
+            s.push_str("|\n");
+        }
+        s.push_str("     +-------+\n");
+        s
+    }
+
+    // Drops the next piece until it comes to rest.
+    fn drop_piece(&self, winds: &[bool]) -> Board {
+        let mut board = self.clone();
+        let mut piece = PIECES[board.piece_i];
+        board.piece_i = (board.piece_i + 1) % PIECES.len();
+        let mut y = board.rows.len() + 3;
+        loop {
+            let right = winds[board.wind_i];
+            board.wind_i = (board.wind_i + 1) % winds.len();
+            let blocked = piece.iter().any(|row| {
+                if right {
+                    row & 0b0000001 != 0
+                } else {
+                    row & 0b1000000 != 0
+                }
+            });
+            if !blocked {
+                let moved = piece.map(|row| if right { row >> 1 } else { row << 1 });
+                if board.fits(&moved, y) {
+                    piece = moved;
+                }
+            }
+            if y == 0 || !board.fits(&piece, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for (i, row) in piece.iter().enumerate() {
+            if *row == 0 {
+                continue;
+            }
+            while board.rows.len() <= y + i {
+                board.rows.push(0);
+            }
+            board.rows[y + i] |= row;
+        }
+        if board.rows.len() > MAX_ROWS {
+            let extra = board.rows.len() - MAX_ROWS;
+            board.rows.drain(..extra);
+            board.floor += extra as u64;
+        }
+        if log_enabled!(Level::Trace) {
+            trace!("\n{}", board.render());
+        }
+        board
+    }
+}
+
+// Plays the first pieces falling, if animations are on.
+fn animate_pieces(start: &Board, winds: &[bool], count: u64) -> Result<()> {
+    if animate::animation().is_none() {
+        return Ok(());
+    }
+    let mut state = (start.clone(), 0);
+    animate::simulate(
+        &mut state,
+        &[('#', Color::rgb(230, 170, 60))],
+        |(board, dropped)| {
+            if *dropped == count.min(ANIMATED_PIECES) {
+                return false;
+            }
+            *board = board.drop_piece(winds);
+            *dropped += 1;
+            true
+        },
+        |(board, _)| Frame::new(board.render()),
+    )?;
+    Ok(())
+}
+
+// Each jet of gas is true if it pushes right.
+fn read_input(path: &str) -> Result<Vec<bool>> {
+    let line = read_lines(path)?.next().context("no input")?;
+    let mut winds = Vec::new();
+    for c in line.chars() {
+        winds.push(match c {
+            '>' => true,
+            '<' => false,
+            _ => bail!("invalid wind {:?} on line {}", c, line.number),
+        });
+    }
+    if winds.is_empty() {
+        bail!("no wind on line {}", line.number);
+    }
+    Ok(winds)
+}
+
+fn simulate(winds: &[bool], count: u64) -> Result<u64> {
+    let start = Board {
+        rows: Vec::new(),
+        floor: 0,
+        piece_i: 0,
+        wind_i: 0,
+    };
+    animate_pieces(&start, winds, count)?;
+    let step = |board: &Board| board.drop_piece(winds);
+    let key = |board: &Board| (board.rows.clone(), board.piece_i, board.wind_i);
+    let result = fast_forward_by_key(start, count, step, key, CycleStrategy::HashMap);
+    let Some(cycle) = result.cycle else {
+        return Ok(result.state.height());
+    };
+
+    // The state doesn't know about the skipped cycles, so add in their height.
+    let mut after = result.state.clone();
+    for _ in 0..cycle.period {
+        after = step(&after);
+    }
+    let cycles = (count - cycle.prefix) / cycle.period;
+    debug!(
+        "found {:?}, skipping {} cycles of {} rows",
+        cycle,
+        cycles,
+        after.height() - result.state.height()
+    );
+    record_counter("cycle_period", cycle.period);
+    Ok(result.state.height() + cycles * (after.height() - result.state.height()))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<bool>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(simulate(input, 2022)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(simulate(input, 1000000000000)?.into())
+    }
+}
@@ -0,0 +1,82 @@
+use advent::common::{read_lines, StrIterator};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{bail, Result};
+use std::collections::{HashSet, VecDeque};
+
+type Coord = (i32, i32, i32);
+
+fn read_input(path: &str) -> Result<Vec<Coord>> {
+    let mut cubes = Vec::new();
+    for line in read_lines(path)? {
+        let v: Vec<i32> = line.tokens(line.split(',')).parse_all()?;
+        let [x, y, z] = v[..] else {
+            bail!("invalid line {}: {:?}", line.number, line.as_ref());
+        };
+        cubes.push((x, y, z));
+    }
+    Ok(cubes)
+}
+
+fn neighbors((x, y, z): Coord) -> [Coord; 6] {
+    [
+        (x - 1, y, z),
+        (x + 1, y, z),
+        (x, y - 1, z),
+        (x, y + 1, z),
+        (x, y, z - 1),
+        (x, y, z + 1),
+    ]
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Coord>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let filled: HashSet<Coord> = input.iter().copied().collect();
+        let faces = input
+            .iter()
+            .flat_map(|c| neighbors(*c))
+            .filter(|n| !filled.contains(n))
+            .count();
+        Ok(faces.into())
+    }
+
+    // Flood fills the air from outside the droplet, and counts faces touching it.
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let filled: HashSet<Coord> = input.iter().copied().collect();
+        let min = |f: fn(&Coord) -> i32| input.iter().map(f).min().unwrap_or(0) - 1;
+        let max = |f: fn(&Coord) -> i32| input.iter().map(f).max().unwrap_or(0) + 1;
+        let lo = (min(|c| c.0), min(|c| c.1), min(|c| c.2));
+        let hi = (max(|c| c.0), max(|c| c.1), max(|c| c.2));
+        let in_bounds = |c: &Coord| {
+            (lo.0..=hi.0).contains(&c.0)
+                && (lo.1..=hi.1).contains(&c.1)
+                && (lo.2..=hi.2).contains(&c.2)
+        };
+
+        let mut outside = HashSet::from([lo]);
+        let mut q = VecDeque::from([lo]);
+        while let Some(c) = q.pop_front() {
+            for n in neighbors(c) {
+                if in_bounds(&n) && !filled.contains(&n) && outside.insert(n) {
+                    q.push_back(n);
+                }
+            }
+        }
+        debug!("{} cubes of air outside", outside.len());
+
+        let faces = input
+            .iter()
+            .flat_map(|c| neighbors(*c))
+            .filter(|n| outside.contains(n))
+            .count();
+        Ok(faces.into())
+    }
+}
@@ -0,0 +1,208 @@
+use advent::checkpoint;
+use advent::common::{read_lines, Template};
+use advent::solution::{record_counter, Answer, Solution};
+use advent::{debug, trace};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+
+// Resources and robots are indexed in the order ore, clay, obsidian, geode.
+const GEODE: usize = 3;
+
+type Inventory = [i64; 4];
+
+#[derive(Debug)]
+pub struct Blueprint {
+    id: i64,
+    // The cost of each kind of robot.
+    costs: [Inventory; 4],
+}
+
+fn read_input(path: &str) -> Result<Vec<Blueprint>> {
+    let template = Template::new(concat!(
+        "Blueprint {id}: ",
+        "Each ore robot costs {ore_ore} ore. ",
+        "Each clay robot costs {clay_ore} ore. ",
+        "Each obsidian robot costs {obsidian_ore} ore and {obsidian_clay} clay. ",
+        "Each geode robot costs {geode_ore} ore and {geode_obsidian} obsidian."
+    ));
+    let mut blueprints = Vec::new();
+    for line in read_lines(path)? {
+        let c = template.captures(&line)?;
+        let blueprint = Blueprint {
+            id: c.get("id")?,
+            costs: [
+                [c.get("ore_ore")?, 0, 0, 0],
+                [c.get("clay_ore")?, 0, 0, 0],
+                [c.get("obsidian_ore")?, c.get("obsidian_clay")?, 0, 0],
+                [c.get("geode_ore")?, 0, c.get("geode_obsidian")?, 0],
+            ],
+        };
+        debug!("{:?}", blueprint);
+        blueprints.push(blueprint);
+    }
+    Ok(blueprints)
+}
+
+// One point in time while following a blueprint.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct Plan {
+    // What's already been produced.
+    inventory: Inventory,
+    // What is produced every minute.
+    robots: Inventory,
+    // How many minutes have passed.
+    time: i64,
+}
+
+impl Plan {
+    fn new() -> Plan {
+        Plan {
+            inventory: [0; 4],
+            robots: [1, 0, 0, 0],
+            time: 0,
+        }
+    }
+
+    // Waits a minute without building anything.
+    fn next(&self) -> Plan {
+        let mut inventory = self.inventory;
+        for (n, robots) in inventory.iter_mut().zip(self.robots) {
+            *n += robots;
+        }
+        Plan {
+            inventory,
+            robots: self.robots,
+            time: self.time + 1,
+        }
+    }
+
+    // Spends a minute building a robot, if there's enough to pay for it.
+    fn build(&self, robot: usize, cost: &Inventory) -> Option<Plan> {
+        if self.inventory.iter().zip(cost).any(|(n, c)| n < c) {
+            return None;
+        }
+        let mut plan = self.next();
+        for (n, c) in plan.inventory.iter_mut().zip(cost) {
+            *n -= c;
+        }
+        plan.robots[robot] += 1;
+        Some(plan)
+    }
+
+    fn score_at(&self, time: i64) -> i64 {
+        self.inventory[GEODE] + self.robots[GEODE] * (time - self.time)
+    }
+
+    // As if a new geode robot were built every minute from now on.
+    fn best_possible_score_at(&self, time: i64) -> i64 {
+        let duration = time - self.time;
+        self.score_at(time) + duration * (duration - 1) / 2
+    }
+}
+
+// How far the search has gotten, so it can be saved to a checkpoint.
+#[derive(Serialize, Deserialize)]
+struct Progress {
+    // The most geodes for each blueprint that's been searched already.
+    geodes: Vec<i64>,
+    // The search of the next blueprint.
+    best: i64,
+    seen: HashSet<Plan>,
+    q: VecDeque<Plan>,
+    nodes: u64,
+}
+
+impl Progress {
+    fn new() -> Progress {
+        Progress {
+            geodes: Vec::new(),
+            best: 0,
+            seen: HashSet::new(),
+            q: VecDeque::from([Plan::new()]),
+            nodes: 0,
+        }
+    }
+
+    fn finish_blueprint(&mut self, geodes: i64) {
+        self.geodes.push(geodes);
+        self.best = 0;
+        self.seen.clear();
+        self.q = VecDeque::from([Plan::new()]);
+    }
+}
+
+impl Blueprint {
+    // The most geodes that the blueprint can open in the given time.
+    // Searches minute by minute, skipping plans that are already known apart from the time.
+    fn search(&self, max_time: i64, key: &str, progress: &mut Progress) -> Result<i64> {
+        loop {
+            checkpoint::check(key, || &*progress)?;
+            let Some(plan) = progress.q.pop_front() else {
+                break;
+            };
+            if plan.best_possible_score_at(max_time) <= progress.best {
+                continue;
+            }
+            if !progress.seen.insert(Plan { time: 0, ..plan }) {
+                continue;
+            }
+            progress.nodes += 1;
+            let score = plan.score_at(max_time);
+            trace!(
+                "{:10} {:?} {:?} t = {} -> {}",
+                progress.q.len(),
+                plan.inventory,
+                plan.robots,
+                plan.time,
+                score
+            );
+            progress.best = progress.best.max(score);
+
+            if plan.time < max_time {
+                progress.q.push_back(plan.next());
+                for (robot, cost) in self.costs.iter().enumerate() {
+                    if let Some(new_plan) = plan.build(robot, cost) {
+                        progress.q.push_back(new_plan);
+                    }
+                }
+            }
+        }
+        debug!("blueprint {}: best = {}", self.id, progress.best);
+        Ok(progress.best)
+    }
+}
+
+// The most geodes for each blueprint, picking up from a checkpoint if there is one.
+fn max_geodes(blueprints: &[Blueprint], max_time: i64) -> Result<Vec<i64>> {
+    let key = format!("{} minutes", max_time);
+    let mut progress = checkpoint::resume(&key)?.unwrap_or_else(Progress::new);
+    while let Some(blueprint) = blueprints.get(progress.geodes.len()) {
+        let geodes = blueprint.search(max_time, &key, &mut progress)?;
+        progress.finish_blueprint(geodes);
+    }
+    record_counter("nodes_visited", progress.nodes);
+    Ok(progress.geodes)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let geodes = max_geodes(input, 24)?;
+        let ans: i64 = input.iter().zip(geodes).map(|(b, g)| b.id * g).sum();
+        Ok(ans.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let first = &input[..input.len().min(3)];
+        let ans: i64 = max_geodes(first, 32)?.into_iter().product();
+        Ok(ans.into())
+    }
+}
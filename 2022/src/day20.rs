@@ -0,0 +1,61 @@
+use advent::common::log::Level;
+use advent::common::{read_lines, StrIterator};
+use advent::solution::{Answer, Solution};
+use advent::{log_enabled, trace};
+use anyhow::{Context, Result};
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+// Mixes the numbers, keeping track of each by its index in the original list.
+fn mix(input: &[i64], key: i64, rounds: usize) -> Result<i64> {
+    let values: Vec<i64> = input.iter().map(|n| n * key).collect();
+    let mut rearranged: Vec<usize> = (0..values.len()).collect();
+    for _ in 0..rounds {
+        for (id, val) in values.iter().enumerate() {
+            let i = rearranged
+                .iter()
+                .position(|n| *n == id)
+                .expect("every id is in the list");
+            // It's -1 because the number being moved isn't in the list while it moves.
+            let len = (values.len() - 1) as i64;
+            let mut j = (i as i64 + val).rem_euclid(len) as usize;
+
+            // No element is allowed to move to the front of the list.
+            if j == 0 && i != 0 {
+                j = values.len() - 1;
+            }
+
+            rearranged.remove(i);
+            rearranged.insert(j, id);
+            if log_enabled!(Level::Trace) {
+                trace!("moved {} from {} to {}: {:?}", val, i, j, rearranged);
+            }
+        }
+    }
+
+    let result: Vec<i64> = rearranged.iter().map(|id| values[*id]).collect();
+    let zero = result
+        .iter()
+        .position(|n| *n == 0)
+        .context("there is no 0")?;
+    let len = result.len();
+    Ok(result[(zero + 1000) % len] + result[(zero + 2000) % len] + result[(zero + 3000) % len])
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        Ok(read_lines(path)?.parse_all()?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(mix(input, 1, 1)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(mix(input, DECRYPTION_KEY, 10)?.into())
+    }
+}
@@ -0,0 +1,200 @@
+use advent::common::{read_lines, split_on, Located, Template};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum Op {
+    Number(i64),
+    Add(String, String),
+    Subtract(String, String),
+    Multiply(String, String),
+    Divide(String, String),
+}
+
+impl Op {
+    fn parse_binary_op(s: &str, op: char) -> Option<(String, String)> {
+        let (s1, s2) = split_on(s, op)?;
+        Some((s1.trim().to_string(), s2.trim().to_string()))
+    }
+
+    fn operands(&self) -> Option<(&str, &str)> {
+        match self {
+            Op::Number(_) => None,
+            Op::Add(x, y) | Op::Subtract(x, y) | Op::Multiply(x, y) | Op::Divide(x, y) => {
+                Some((x, y))
+            }
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Op> {
+        Ok(if let Some((s1, s2)) = Op::parse_binary_op(s, '+') {
+            Op::Add(s1, s2)
+        } else if let Some((s1, s2)) = Op::parse_binary_op(s, '-') {
+            Op::Subtract(s1, s2)
+        } else if let Some((s1, s2)) = Op::parse_binary_op(s, '*') {
+            Op::Multiply(s1, s2)
+        } else if let Some((s1, s2)) = Op::parse_binary_op(s, '/') {
+            Op::Divide(s1, s2)
+        } else {
+            Op::Number(s.parse().with_context(|| format!("bad number: {}", s))?)
+        })
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Op::Number(n) => write!(f, "{}", n),
+            Op::Add(a, b) => write!(f, "{} + {}", a, b),
+            Op::Subtract(a, b) => write!(f, "{} - {}", a, b),
+            Op::Multiply(a, b) => write!(f, "{} * {}", a, b),
+            Op::Divide(a, b) => write!(f, "{} / {}", a, b),
+        }
+    }
+}
+
+const LINE: &str = "{monkey}: {op}";
+
+fn parse_line<L: Located + ?Sized>(template: &Template, line: &L) -> Result<(String, Op)> {
+    let c = template.captures(line)?;
+    Ok((c.str("monkey").to_owned(), c.get("op")?))
+}
+
+fn read_input(path: &str) -> Result<HashMap<String, Op>> {
+    let template = Template::new(LINE);
+    let mut m = HashMap::new();
+    for line in read_lines(path)? {
+        let (monkey, op) = parse_line(&template, &line)?;
+        debug!("{} -> {}", monkey, op);
+        m.insert(monkey, op);
+    }
+    Ok(m)
+}
+
+fn lookup<'a>(m: &'a HashMap<String, Op>, s: &str) -> Result<&'a Op> {
+    m.get(s).ok_or_else(|| anyhow!("unable to find {}", s))
+}
+
+// Computes what a monkey yells. If `unknown` is given, that monkey's number doesn't count,
+// and anything that depends on it is None.
+fn compute(m: &HashMap<String, Op>, s: &str, unknown: Option<&str>) -> Result<Option<i64>> {
+    if Some(s) == unknown {
+        return Ok(None);
+    }
+    let op = lookup(m, s)?;
+    if let Op::Number(n) = op {
+        return Ok(Some(*n));
+    }
+    let (x, y) = op.operands().expect("only numbers have no operands");
+    let (Some(x), Some(y)) = (compute(m, x, unknown)?, compute(m, y, unknown)?) else {
+        return Ok(None);
+    };
+    Ok(Some(match op {
+        Op::Number(_) => unreachable!(),
+        Op::Add(_, _) => x + y,
+        Op::Subtract(_, _) => x - y,
+        Op::Multiply(_, _) => x * y,
+        Op::Divide(_, _) => x / y,
+    }))
+}
+
+// Finds what humn has to yell for monkey k to yell v.
+fn solve(m: &HashMap<String, Op>, k: &str, v: i64) -> Result<i64> {
+    if k == "humn" {
+        return Ok(v);
+    }
+
+    let node = lookup(m, k)?;
+    let (left, right) = node
+        .operands()
+        .ok_or_else(|| anyhow!("found number when solving"))?;
+
+    // The root checks for equality, which is the same as the difference being 0.
+    let root_alt = Op::Subtract(left.to_string(), right.to_string());
+    let node = if k == "root" { &root_alt } else { node };
+
+    let left_result = compute(m, left, Some("humn"))?;
+    let right_result = compute(m, right, Some("humn"))?;
+    debug!(
+        "trying to solve {} = {} = {} where {}={:?} and {}={:?}",
+        k, node, v, left, left_result, right, right_result
+    );
+
+    match (left_result, right_result) {
+        (Some(_), Some(_)) => bail!("humn doesn't exist in either branch"),
+        (Some(left_value), None) => {
+            let expected = match node {
+                Op::Number(_) => unreachable!(),
+                Op::Add(_, _) => v - left_value,
+                Op::Subtract(_, _) => left_value - v,
+                Op::Multiply(_, _) => v / left_value,
+                Op::Divide(_, _) => left_value / v,
+            };
+            solve(m, right, expected)
+        }
+        (None, Some(right_value)) => {
+            let expected = match node {
+                Op::Number(_) => unreachable!(),
+                Op::Add(_, _) => v - right_value,
+                Op::Subtract(_, _) => v + right_value,
+                Op::Multiply(_, _) => v / right_value,
+                Op::Divide(_, _) => v * right_value,
+            };
+            solve(m, left, expected)
+        }
+        (None, None) => bail!("humn exists in two branches"),
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, Op>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let ans = compute(input, "root", None)?.expect("nothing is unknown");
+        Ok(ans.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(solve(input, "root", 0)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert!(
+            matches!(Op::from_str("abcd + efgh").unwrap(), Op::Add(x1, x2) if x1 == "abcd" && x2 == "efgh")
+        );
+        assert!(
+            matches!(Op::from_str("abcd - efgh").unwrap(), Op::Subtract(x1, x2) if x1 == "abcd" && x2 == "efgh")
+        );
+        assert!(
+            matches!(Op::from_str("abcd * efgh").unwrap(), Op::Multiply(x1, x2) if x1 == "abcd" && x2 == "efgh")
+        );
+        assert!(
+            matches!(Op::from_str("abcd / efgh").unwrap(), Op::Divide(x1, x2) if x1 == "abcd" && x2 == "efgh")
+        );
+        assert!(matches!(Op::from_str("123").unwrap(), Op::Number(x) if x == 123));
+
+        let (monkey, op) = parse_line(&Template::new(LINE), "abcd: efgh + ijkl").unwrap();
+        assert_eq!(monkey, "abcd");
+        assert!(matches!(op, Op::Add(x, y) if x == "efgh" && y == "ijkl"));
+    }
+}
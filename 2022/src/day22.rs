@@ -1,23 +1,10 @@
-use anyhow::{anyhow, Context, Result};
-use clap::Parser;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-#[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Args {
-    #[arg(short, long)]
-    input: String,
-
-    #[arg(long)]
-    debug: bool,
-
-    #[arg(long)]
-    part2: bool,
-
-    #[arg(long)]
-    shape2: bool,
-}
+use advent::common::animate::{self, Frame};
+use advent::common::log::Level;
+use advent::common::render::Color;
+use advent::common::{read_sections, Token};
+use advent::solution::{Answer, Solution};
+use advent::{debug, log_enabled, trace};
+use anyhow::{bail, Result};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 enum Orientation {
@@ -55,12 +42,12 @@ impl Orientation {
         }
     }
 
-    fn to_string(&self) -> String {
+    fn symbol(&self) -> char {
         match self {
-            Orientation::Up => "^".to_string(),
-            Orientation::Down => "v".to_string(),
-            Orientation::Left => "<".to_string(),
-            Orientation::Right => ">".to_string(),
+            Orientation::Up => '^',
+            Orientation::Down => 'v',
+            Orientation::Left => '<',
+            Orientation::Right => '>',
         }
     }
 }
@@ -71,24 +58,23 @@ struct MapRow {
 }
 
 impl MapRow {
-    fn print(&self, pos: Option<(usize, &Orientation)>) {
-        for _ in 0..self.offset {
-            print!(" ");
-        }
+    fn render(&self, pos: Option<(usize, &Orientation)>) -> String {
+        let mut s = " ".repeat(self.offset);
         for (x, b) in self.data.iter().enumerate() {
-            if let Some((px, d)) = pos {
-                if px == x + self.offset {
-                    print!("{}", d.to_string());
-                    continue;
-                }
+            match pos {
+                Some((px, d)) if px == x + self.offset => s.push(d.symbol()),
+                _ => s.push(if *b { '#' } else { '.' }),
             }
-            print!("{}", if *b { "#" } else { "." });
         }
+        s
     }
 }
 
-struct Map {
+pub struct Map {
     rows: Vec<MapRow>,
+    // Whether the faces are laid out like the real input, 4 tall and 3 wide,
+    // rather than like the sample, 3 tall and 4 wide.
+    shape2: bool,
 }
 
 enum Face {
@@ -531,9 +517,8 @@ impl Map {
         &self,
         pos: (usize, usize),
         dir: &Orientation,
-        shape2: bool,
     ) -> Option<((usize, usize), Orientation)> {
-        if shape2 {
+        if self.shape2 {
             self.wrap2(pos, dir)
         } else {
             match dir {
@@ -550,18 +535,17 @@ impl Map {
         pos: (usize, usize),
         dir: &Orientation,
         dist: i64,
-        shape2: bool,
     ) -> ((usize, usize), Orientation) {
         let mut pos = pos;
         let mut dir = *dir;
         for _ in 0..dist {
-            let (new_pos, new_dir) = self.wrap(pos, &dir, shape2).unwrap_or_else(|| match dir {
+            let (new_pos, new_dir) = self.wrap(pos, &dir).unwrap_or_else(|| match dir {
                 Orientation::Up => ((pos.0, pos.1 - 1), Orientation::Up),
                 Orientation::Down => ((pos.0, pos.1 + 1), Orientation::Down),
                 Orientation::Left => ((pos.0 - 1, pos.1), Orientation::Left),
                 Orientation::Right => ((pos.0 + 1, pos.1), Orientation::Right),
             });
-            let new_row = self.rows.get(new_pos.1).unwrap();
+            let new_row = &self.rows[new_pos.1];
             if new_row.data[new_pos.0 - new_row.offset] {
                 break;
             }
@@ -571,22 +555,20 @@ impl Map {
         (pos, dir)
     }
 
-    fn print(&self, pos: (usize, usize), dir: &Orientation) {
+    fn render(&self, pos: (usize, usize), dir: &Orientation) -> String {
         let (px, py) = pos;
+        let mut s = String::new();
         for (y, row) in self.rows.iter().enumerate() {
-            if py == y {
-                row.print(Some((px, dir)));
-            } else {
-                row.print(None);
-            }
-            println!("");
+            s.push_str(&row.render((py == y).then_some((px, dir))));
+            s.push('\n');
         }
+        s
     }
 
     fn walk_right(&self, pos: (usize, usize), dist: i64) -> (usize, usize) {
         let mut pos = pos;
         for _ in 0..dist {
-            let row = self.rows.get(pos.1).unwrap();
+            let row = &self.rows[pos.1];
             let new_x = if pos.0 == row.offset + row.data.len() - 1 {
                 row.offset
             } else {
@@ -603,7 +585,7 @@ impl Map {
     fn walk_left(&self, pos: (usize, usize), dist: i64) -> (usize, usize) {
         let mut pos = pos;
         for _ in 0..dist {
-            let row = self.rows.get(pos.1).unwrap();
+            let row = &self.rows[pos.1];
             let new_x = if pos.0 == row.offset {
                 (row.offset + row.data.len()) - 1
             } else {
@@ -700,10 +682,9 @@ impl Map {
         dir: &Orientation,
         dist: i64,
         part2: bool,
-        shape2: bool,
     ) -> ((usize, usize), Orientation) {
         if part2 {
-            self.walk2(pos, dir, dist, shape2)
+            self.walk2(pos, dir, dist)
         } else {
             self.walk1(pos, dir, dist)
         }
@@ -711,152 +692,133 @@ impl Map {
 }
 
 #[derive(Debug)]
-enum Instruction {
+pub enum Instruction {
     Forward(i64),
     Right,
     Left,
 }
 
 impl Instruction {
-    fn parse(s: &str) -> Result<Option<(Instruction, &str)>> {
-        return if s == "" {
-            Ok(None)
-        } else if let Some(i) = s.find(|c| c == 'R' || c == 'L') {
+    // Parses the first instruction in `token`, and returns it with the rest of the token.
+    fn parse(token: Token) -> Result<Option<(Instruction, Token)>> {
+        let s = token.text();
+        Ok(if s.is_empty() {
+            None
+        } else if let Some(i) = s.find(['R', 'L']) {
             if i == 0 {
                 let (d, s) = s.split_at(1);
                 let instruction = match d {
                     "R" => Instruction::Right,
-                    "L" => Instruction::Left,
-                    _ => panic!("invalid instruction: {}", d),
+                    _ => Instruction::Left,
                 };
-                Ok(Some((instruction, s)))
+                Some((instruction, token.slice(s)))
             } else {
                 let (sn, s) = s.split_at(i);
-                let n = sn.parse::<i64>()?;
-                Ok(Some((Instruction::Forward(n), s)))
+                let n = token.slice(sn).parse()?;
+                Some((Instruction::Forward(n), token.slice(s)))
             }
         } else {
-            Ok(Some((Instruction::Forward(s.parse::<i64>()?), "")))
-        };
+            let n = token.parse()?;
+            Some((Instruction::Forward(n), token.slice(&s[s.len()..])))
+        })
     }
 
-    fn parse_list(s: &str) -> Result<Vec<Instruction>> {
+    fn parse_list(token: Token) -> Result<Vec<Instruction>> {
         let mut v = Vec::new();
-        let mut ss = s;
-        while let Some((instruction, s)) = Instruction::parse(ss)? {
+        let mut rest = token;
+        while let Some((instruction, token)) = Instruction::parse(rest)? {
             v.push(instruction);
-            ss = s;
+            rest = token;
         }
         Ok(v)
     }
 }
 
-fn read_input(path: &str, _debug: bool) -> Result<(Map, Vec<Instruction>)> {
-    let file = File::open(path).with_context(|| format!("unable to open file {:?}", path))?;
-    let mut r = BufReader::new(file);
-    let mut rows = Vec::new();
-    let mut instructions = None;
-    let mut in_map = true;
-    loop {
-        let mut line = String::new();
-        let n = r.read_line(&mut line).unwrap();
-        let line = line.trim_end();
-
-        if line == "" {
-            in_map = false;
-            if n == 0 {
-                break;
-            }
-            continue;
-        }
+fn read_input(path: &str) -> Result<(Map, Vec<Instruction>)> {
+    let sections = read_sections(path)?;
+    let [map_lines, instruction_lines] = &sections[..] else {
+        bail!(
+            "expected a map and instructions, got {} sections",
+            sections.len()
+        );
+    };
 
-        if in_map {
-            let mut data = Vec::new();
-            let mut offset = 0;
-            for c in line.chars() {
-                match c {
-                    ' ' => {
-                        offset = offset + 1;
-                    }
-                    '.' => {
-                        data.push(false);
-                    }
-                    '#' => {
-                        data.push(true);
-                    }
-                    _ => {
-                        return Err(anyhow!("invalid character in map: {}", c));
-                    }
-                }
-            }
-            rows.push(MapRow { offset, data });
-        } else {
-            if instructions.is_some() {
-                return Err(anyhow!("duplicate instructions: {}", line));
-            }
-            instructions = Some(Instruction::parse_list(line)?);
-        }
-    }
-    let m = Map { rows };
-    return if let Some(instructions) = instructions {
-        Ok((m, instructions))
-    } else {
-        Err(anyhow!("missing instructions"))
+    let mut rows = Vec::new();
+    for line in map_lines {
+        let mut data = Vec::new();
+        let mut offset = 0;
+        for c in line.trim_end().chars() {
+            match c {
+                ' ' => offset += 1,
+                '.' => data.push(false),
+                '#' => data.push(true),
+                _ => bail!("invalid character in map on line {}: {:?}", line.number, c),
+            }
+        }
+        rows.push(MapRow { offset, data });
+    }
+    if rows.is_empty() {
+        bail!("the map is empty");
+    }
+    let width = rows
+        .iter()
+        .map(|row| row.offset + row.data.len())
+        .max()
+        .unwrap_or(0);
+    let shape2 = rows.len() > width;
+
+    let [line] = &instruction_lines[..] else {
+        bail!("expected one line of instructions");
     };
+    let instructions = Instruction::parse_list(line.token(line.trim()))?;
+    Ok((Map { rows, shape2 }, instructions))
 }
 
-fn process(args: &Args) -> Result<()> {
-    if args.debug {
-        println!("reading input...");
-    }
-    let (map, instructions) = read_input(&args.input, args.debug)?;
+const YOU: Color = Color::rgb(255, 80, 80);
+
+fn follow(map: &Map, instructions: &[Instruction], part2: bool) -> Result<usize> {
+    debug!("rows = {}, shape2 = {}", map.rows.len(), map.shape2);
+    let mut state = (map.initial_pos(), Orientation::Right, instructions.iter());
+    animate::simulate(
+        &mut state,
+        &[('^', YOU), ('v', YOU), ('<', YOU), ('>', YOU)],
+        |(pos, dir, steps)| {
+            let Some(step) = steps.next() else {
+                return false;
+            };
+            trace!("facing {:?} @ ({}, {}): {:?}", dir, pos.0, pos.1, step);
+            match step {
+                Instruction::Forward(dist) => (*pos, *dir) = map.walk(*pos, dir, *dist, part2),
+                Instruction::Right => *dir = dir.turn_right(),
+                Instruction::Left => *dir = dir.turn_left(),
+            }
+            true
+        },
+        |(pos, dir, _)| Frame::new(map.render(*pos, dir)).focus(pos.1, pos.0),
+    )?;
+    let (pos, dir, _) = state;
+    if log_enabled!(Level::Debug) {
+        debug!("\n{}", map.render(pos, &dir));
+    }
+    Ok(1000 * (pos.1 + 1) + 4 * (pos.0 + 1) + dir.score())
+}
 
-    let mut pos = map.initial_pos();
-    let mut dir = Orientation::Right;
-    if args.debug {
-        map.print(pos, &dir);
-        println!("{:?}", instructions);
-        println!("rows = {}", map.rows.len());
-        println!("face_size = {}", map.rows.len() / 3);
-    }
+pub struct Day22;
 
-    for step in instructions {
-        if args.debug {
-            println!("Facing {:?} @ ({}, {})", dir, pos.0, pos.1);
-            println!("Step: {:?}", step);
-        }
-        match step {
-            Instruction::Forward(dist) => {
-                (pos, dir) = map.walk(pos, &dir, dist, args.part2, args.shape2);
-            }
-            Instruction::Right => {
-                dir = dir.turn_right();
-            }
-            Instruction::Left => {
-                dir = dir.turn_left();
-            }
-        }
-        if args.debug {
-            map.print(pos, &dir);
-            println!("");
-        }
-    }
-    if args.debug {
-        println!("Facing {:?} @ ({}, {})", dir, pos.0, pos.1);
-    }
+impl Solution for Day22 {
+    type Input = (Map, Vec<Instruction>);
 
-    let ans = 1000 * (pos.1 + 1) + 4 * (pos.0 + 1) + dir.score();
-    println!("ans = {}", ans);
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
 
-    Ok(())
-}
+    fn part1(&self, (map, instructions): &Self::Input) -> Result<Answer> {
+        Ok(follow(map, instructions, false)?.into())
+    }
 
-fn main() {
-    let args = Args::parse();
-    match process(&args) {
-        Ok(_) => (),
-        Err(error) => panic!("{:?}", error),
-    };
+    fn part2(&self, (map, instructions): &Self::Input) -> Result<Answer> {
+        Ok(follow(map, instructions, true)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -865,8 +827,9 @@ mod tests {
 
     #[test]
     fn test_wrap() {
-        let (m, _) = read_input("./empty4x4.txt", true).unwrap();
+        let (m, _) = read_input("data/22/empty4x4.txt").unwrap();
         assert_eq!(m.rows.len(), 12);
+        assert!(!m.shape2);
 
         let cases: Vec<(usize, usize, Orientation, usize, usize, Orientation)> = vec![
             (8, 0, Orientation::Right, 9, 0, Orientation::Right),
@@ -894,7 +857,7 @@ mod tests {
         ];
         for (i, case) in cases.iter().enumerate() {
             let (start_x, start_y, start_dir, exp_x, exp_y, exp_dir) = case;
-            let ((act_x, act_y), act_dir) = m.walk((*start_x, *start_y), start_dir, 1, true, false);
+            let ((act_x, act_y), act_dir) = m.walk((*start_x, *start_y), start_dir, 1, true);
             println!(
                 "case {}: walking from ({}, {}) {:?}: = ({}, {}) {:?}; want ({} {}) {:?};",
                 i, start_x, start_y, &start_dir, act_x, act_y, &act_dir, exp_x, exp_y, &exp_dir,
@@ -907,8 +870,9 @@ mod tests {
 
     #[test]
     fn test_shape2() {
-        let (m, _) = read_input("./empty4x4_2.txt", true).unwrap();
+        let (m, _) = read_input("data/22/empty4x4_2.txt").unwrap();
         assert_eq!(m.rows.len(), 16);
+        assert!(m.shape2);
 
         let cases: Vec<(usize, usize, Orientation, usize, usize, Orientation)> = vec![
             (4, 0, Orientation::Right, 5, 0, Orientation::Right),
@@ -936,7 +900,7 @@ mod tests {
         ];
         for (i, case) in cases.iter().enumerate() {
             let (start_x, start_y, start_dir, exp_x, exp_y, exp_dir) = case;
-            let ((act_x, act_y), act_dir) = m.walk((*start_x, *start_y), start_dir, 1, true, true);
+            let ((act_x, act_y), act_dir) = m.walk((*start_x, *start_y), start_dir, 1, true);
             println!(
                 "case {}: walking from ({}, {}) {:?}: = ({}, {}) {:?}; want ({} {}) {:?};",
                 i, start_x, start_y, &start_dir, act_x, act_y, &act_dir, exp_x, exp_y, &exp_dir,
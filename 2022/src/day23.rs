@@ -0,0 +1,228 @@
+use advent::common::animate::{self, Frame};
+use advent::common::log::Level;
+use advent::common::read_lines;
+use advent::common::render::Color;
+use advent::solution::{Answer, Solution};
+use advent::{log_enabled, trace};
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+
+#[derive(Copy, Clone)]
+enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    fn next(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+}
+
+struct Elf {
+    pos: (i64, i64),
+    next: (i64, i64),
+    active: bool,
+}
+
+#[derive(Debug)]
+struct Bounds {
+    min_x: i64,
+    min_y: i64,
+    max_x: i64,
+    max_y: i64,
+}
+
+impl Bounds {
+    fn of<'a>(positions: impl Iterator<Item = &'a (i64, i64)>) -> Bounds {
+        let mut bounds = Bounds {
+            min_x: i64::MAX,
+            min_y: i64::MAX,
+            max_x: i64::MIN,
+            max_y: i64::MIN,
+        };
+        for (x, y) in positions {
+            bounds.min_x = bounds.min_x.min(*x);
+            bounds.min_y = bounds.min_y.min(*y);
+            bounds.max_x = bounds.max_x.max(*x);
+            bounds.max_y = bounds.max_y.max(*y);
+        }
+        bounds
+    }
+}
+
+struct Map {
+    elves: Vec<Elf>,
+    dir: Direction,
+}
+
+impl Map {
+    fn new(positions: &[(i64, i64)]) -> Map {
+        let elves = positions
+            .iter()
+            .map(|pos| Elf {
+                pos: *pos,
+                next: *pos,
+                active: true,
+            })
+            .collect();
+        Map {
+            elves,
+            dir: Direction::North,
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        Bounds::of(self.elves.iter().map(|elf| &elf.pos))
+    }
+
+    // Runs one round, and returns whether any elf wanted to move.
+    fn step(&mut self) -> bool {
+        let mut some_active = false;
+
+        // Gather all of the start positions.
+        let start_pos: HashSet<(i64, i64)> = self.elves.iter().map(|elf| elf.pos).collect();
+
+        for elf in self.elves.iter_mut() {
+            let (x, y) = elf.pos;
+            elf.active = [
+                (x - 1, y - 1),
+                (x, y - 1),
+                (x + 1, y - 1),
+                (x - 1, y),
+                (x + 1, y),
+                (x - 1, y + 1),
+                (x, y + 1),
+                (x + 1, y + 1),
+            ]
+            .iter()
+            .any(|spot| start_pos.contains(spot));
+            some_active = some_active || elf.active;
+        }
+
+        // Find all the proposals for next spots.
+        let mut proposals = HashSet::new();
+        let mut conflicts = HashSet::new();
+        for elf in self.elves.iter_mut() {
+            if !elf.active {
+                continue;
+            }
+            let (x, y) = elf.pos;
+            elf.next = elf.pos;
+            let mut dir = self.dir;
+            for _ in 0..4 {
+                let spots = match dir {
+                    Direction::North => [(x - 1, y - 1), (x, y - 1), (x + 1, y - 1)],
+                    Direction::South => [(x - 1, y + 1), (x, y + 1), (x + 1, y + 1)],
+                    Direction::East => [(x + 1, y - 1), (x + 1, y), (x + 1, y + 1)],
+                    Direction::West => [(x - 1, y - 1), (x - 1, y), (x - 1, y + 1)],
+                };
+                if !spots.iter().any(|spot| start_pos.contains(spot)) {
+                    // The middle spot is straight ahead.
+                    elf.next = spots[1];
+                    if !proposals.insert(elf.next) {
+                        conflicts.insert(elf.next);
+                    }
+                    break;
+                }
+                dir = dir.next();
+            }
+        }
+
+        // Commit the moves, except where more than one elf wants the same spot.
+        for elf in self.elves.iter_mut() {
+            if elf.active && !conflicts.contains(&elf.next) {
+                elf.pos = elf.next;
+            }
+        }
+        self.dir = self.dir.next();
+        some_active
+    }
+
+    fn render(&self) -> String {
+        let elves: HashSet<(i64, i64)> = self.elves.iter().map(|elf| elf.pos).collect();
+        let bounds = self.bounds();
+        let mut s = String::new();
+        for y in bounds.min_y..=bounds.max_y {
+            for x in bounds.min_x..=bounds.max_x {
+                s.push(if elves.contains(&(x, y)) { '#' } else { '.' });
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    // The number of empty spots in the rectangle containing every elf.
+    fn score(&self) -> i64 {
+        let bounds = self.bounds();
+        let width = (bounds.max_x - bounds.min_x) + 1;
+        let height = (bounds.max_y - bounds.min_y) + 1;
+        width * height - (self.elves.len() as i64)
+    }
+}
+
+fn read_input(path: &str) -> Result<Vec<(i64, i64)>> {
+    let mut elves = Vec::new();
+    for (y, line) in read_lines(path)?.enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                '.' => {}
+                '#' => elves.push((x as i64, y as i64)),
+                _ => bail!("unknown char on line {}: {:?}", line.number, c),
+            }
+        }
+    }
+    Ok(elves)
+}
+
+// Runs rounds until `last_round`, or until no elf wants to move if there's no limit.
+// Returns how many rounds had an elf move, animating each one if animations are on.
+fn run(map: &mut Map, last_round: Option<usize>) -> Result<usize> {
+    let mut state = (map, 0);
+    animate::simulate(
+        &mut state,
+        &[('#', Color::rgb(80, 200, 80))],
+        |(map, round)| {
+            if Some(*round) == last_round {
+                return false;
+            }
+            *round += 1;
+            let moved = map.step();
+            if log_enabled!(Level::Trace) {
+                trace!("== End of Round {} ==\n{}", round, map.render());
+            }
+            moved || last_round.is_some()
+        },
+        |(map, _)| Frame::new(map.render()),
+    )
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<(i64, i64)>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let mut map = Map::new(input);
+        run(&mut map, Some(10))?;
+        Ok(map.score().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let mut map = Map::new(input);
+        // The answer is the first round where nobody moved.
+        let rounds = run(&mut map, None)? + 1;
+        Ok(rounds.into())
+    }
+}
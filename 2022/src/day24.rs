@@ -0,0 +1,181 @@
+use advent::common::{read_grid, resumable_bfs, Graph};
+use advent::debug;
+use advent::solution::{record_counter, Answer, Solution};
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::HashMap;
+
+enum Tile {
+    Empty,
+    Wall,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Result<Self> {
+        Ok(match c {
+            '.' => Tile::Empty,
+            '#' => Tile::Wall,
+            '^' => Tile::Up,
+            'v' => Tile::Down,
+            '<' => Tile::Left,
+            '>' => Tile::Right,
+            _ => bail!("invalid character: {:?}", c),
+        })
+    }
+}
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    // The starting positions of the blizzards, by the column or row they move along.
+    up_blizzards: HashMap<usize, Vec<usize>>,
+    down_blizzards: HashMap<usize, Vec<usize>>,
+    left_blizzards: HashMap<usize, Vec<usize>>,
+    right_blizzards: HashMap<usize, Vec<usize>>,
+}
+
+fn read_input(path: &str) -> Result<Map> {
+    let tiles = read_grid(path, Tile::from_char)?;
+    if tiles.rows() < 3 || tiles.columns() < 3 {
+        bail!("the valley is too small");
+    }
+
+    let mut up_blizzards: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut down_blizzards: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut left_blizzards: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut right_blizzards: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (y, x, tile) in tiles.enumerate() {
+        match tile {
+            Tile::Empty | Tile::Wall => {}
+            Tile::Up => up_blizzards.entry(x).or_default().push(y),
+            Tile::Down => down_blizzards.entry(x).or_default().push(y),
+            Tile::Left => left_blizzards.entry(y).or_default().push(x),
+            Tile::Right => right_blizzards.entry(y).or_default().push(x),
+        }
+    }
+
+    Ok(Map {
+        width: tiles.columns(),
+        height: tiles.rows(),
+        up_blizzards,
+        down_blizzards,
+        left_blizzards,
+        right_blizzards,
+    })
+}
+
+impl Map {
+    fn start(&self) -> (usize, usize) {
+        (1, 0)
+    }
+
+    fn end(&self) -> (usize, usize) {
+        (self.width - 2, self.height - 1)
+    }
+
+    // Where a blizzard that started at `p` is after moving `t` steps, wrapping inside the walls.
+    fn wrap(p: usize, t: i64, size: usize) -> usize {
+        ((p as i64 - 1 + t).rem_euclid(size as i64 - 2) + 1) as usize
+    }
+
+    fn has_blizzard(&self, x: usize, y: usize, t: usize) -> bool {
+        let t = t as i64;
+        let hits = |blizzards: &HashMap<usize, Vec<usize>>, line, p, dt, size| {
+            blizzards
+                .get(&line)
+                .is_some_and(|ps| ps.iter().any(|&p0| Map::wrap(p0, dt, size) == p))
+        };
+        hits(&self.up_blizzards, x, y, -t, self.height)
+            || hits(&self.down_blizzards, x, y, t, self.height)
+            || hits(&self.left_blizzards, y, x, -t, self.width)
+            || hits(&self.right_blizzards, y, x, t, self.width)
+    }
+
+    fn is_traversable(&self, x: usize, y: usize, t: usize) -> bool {
+        if (x, y) == self.start() || (x, y) == self.end() {
+            true
+        } else if x == 0 || y == 0 || x >= self.width - 1 || y >= self.height - 1 {
+            false
+        } else {
+            !self.has_blizzard(x, y, t)
+        }
+    }
+}
+
+// Searches for a way through the valley to the goal, where each node is (x, y, time).
+struct Trip<'a> {
+    map: &'a Map,
+    goal: (usize, usize),
+}
+
+impl<'a> Graph for Trip<'a> {
+    type Node = (usize, usize, usize);
+
+    fn successors(&self, &(x, y, t): &Self::Node) -> Vec<(Self::Node, i64)> {
+        // Wait, up, down, left, right.
+        let mut moves = vec![(x, y), (x, y + 1), (x + 1, y)];
+        if y > 0 {
+            moves.push((x, y - 1));
+        }
+        if x > 0 {
+            moves.push((x - 1, y));
+        }
+        moves
+            .into_iter()
+            .filter(|&(x, y)| self.map.is_traversable(x, y, t + 1))
+            .map(|(x, y)| ((x, y, t + 1), 1))
+            .collect()
+    }
+
+    fn is_goal(&self, &(x, y, _): &Self::Node) -> bool {
+        (x, y) == self.goal
+    }
+}
+
+// Returns the time it is when reaching the goal.
+fn travel(
+    map: &Map,
+    from: (usize, usize),
+    to: (usize, usize),
+    t: usize,
+    expanded: &mut u64,
+) -> Result<usize> {
+    let trip = Trip { map, goal: to };
+    let key = format!("{:?} to {:?} at t = {}", from, to, t);
+    let result = resumable_bfs(&trip, [(from.0, from.1, t)], &key)?
+        .ok_or_else(|| anyhow!("no way from {:?} to {:?}", from, to))?;
+    debug!("{:?} to {:?} at t = {}: {} steps", from, to, t, result.cost);
+    *expanded += result.stats.expanded as u64;
+    let (_, _, end_t) = result.path.last().context("the path is empty")?;
+    Ok(*end_t)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Map;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let mut expanded = 0;
+        let t = travel(input, input.start(), input.end(), 0, &mut expanded)?;
+        record_counter("nodes_expanded", expanded);
+        Ok(t.into())
+    }
+
+    // Goes back for the snacks, then back to the end again.
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let mut expanded = 0;
+        let t = travel(input, input.start(), input.end(), 0, &mut expanded)?;
+        let t = travel(input, input.end(), input.start(), t, &mut expanded)?;
+        let t = travel(input, input.start(), input.end(), t, &mut expanded)?;
+        record_counter("nodes_expanded", expanded);
+        Ok(t.into())
+    }
+}
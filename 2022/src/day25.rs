@@ -0,0 +1,70 @@
+use advent::common::read_lines;
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{bail, Result};
+
+// Decodes a SNAFU number, which is base 5 with digits from -2 to 2.
+fn decode(s: &str) -> Result<i64> {
+    let mut n = 0;
+    for c in s.chars() {
+        n = n * 5
+            + match c {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => bail!("invalid character: {:?}", c),
+            };
+    }
+    Ok(n)
+}
+
+fn encode(n: i64) -> String {
+    let mut n = n.abs();
+    let mut v = Vec::new();
+    while n != 0 {
+        let digit = match n % 5 {
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            3 => '=',
+            _ => '-',
+        };
+        // The negative digits borrow from the next place up.
+        n = (n + 2) / 5;
+        v.push(digit);
+    }
+    if v.is_empty() {
+        v.push('0');
+    }
+    v.into_iter().rev().collect()
+}
+
+fn read_input(path: &str) -> Result<Vec<i64>> {
+    let mut v = Vec::new();
+    for line in read_lines(path)? {
+        let n = line.token(&line).parse_with(decode)?;
+        debug!("{} => {} => {}", line.as_ref(), n, encode(n));
+        v.push(n);
+    }
+    Ok(v)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<i64>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(encode(input.iter().sum()).into())
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+        bail!("day 25 has no part 2")
+    }
+}
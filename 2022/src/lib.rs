@@ -0,0 +1,55 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+use advent::solution::Registry;
+
+pub fn register(registry: &mut Registry) {
+    registry.add(2022, 1, day01::Day01);
+    registry.add(2022, 2, day02::Day02);
+    registry.add(2022, 3, day03::Day03);
+    registry.add(2022, 4, day04::Day04);
+    registry.add(2022, 5, day05::Day05);
+    registry.add(2022, 6, day06::Day06);
+    registry.add(2022, 7, day07::Day07);
+    registry.add(2022, 8, day08::Day08);
+    registry.add(2022, 9, day09::Day09);
+    registry.add(2022, 10, day10::Day10);
+    registry.add(2022, 11, day11::Day11);
+    registry.add(2022, 12, day12::Day12);
+    registry.add(2022, 13, day13::Day13);
+    registry.add(2022, 14, day14::Day14);
+    registry.add(2022, 15, day15::Day15);
+    registry.add(2022, 16, day16::Day16);
+    registry.add(2022, 17, day17::Day17);
+    registry.add(2022, 18, day18::Day18);
+    registry.add(2022, 19, day19::Day19);
+    registry.add(2022, 20, day20::Day20);
+    registry.add(2022, 21, day21::Day21);
+    registry.add(2022, 22, day22::Day22);
+    registry.add(2022, 23, day23::Day23);
+    registry.add(2022, 24, day24::Day24);
+    registry.add(2022, 25, day25::Day25);
+}
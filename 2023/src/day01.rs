@@ -0,0 +1,104 @@
+use advent::common::read_lines;
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, Context, Result};
+
+fn read_input(path: &str) -> Result<Vec<String>> {
+    Ok(read_lines(path)?.map(|line| line.text).collect())
+}
+
+fn read_number_part1(line: &str) -> Result<u32> {
+    let p1 = line
+        .find(|c: char| c.is_ascii_digit())
+        .context(format!("no digit in line {:?}", line))?;
+    let p2 = line
+        .rfind(|c: char| c.is_ascii_digit())
+        .context(format!("no digit in line {:?}", line))?;
+
+    let b = line.as_bytes();
+    let c1 = b[p1] as char;
+    let c2 = b[p2] as char;
+
+    let n1 = c1.to_digit(10).context(format!("invalid digit {:?}", c1))?;
+    let n2 = c2.to_digit(10).context(format!("invalid digit {:?}", c2))?;
+
+    Ok(n1 * 10 + n2)
+}
+
+fn read_number_part2(line: &str) -> Result<u32> {
+    let mut n1: Option<u32> = None;
+    let mut n2: Option<u32> = None;
+
+    let mut s: &str = line;
+    while !s.is_empty() {
+        let n = if s.chars().next().unwrap().is_ascii_digit() {
+            Some(s.chars().next().unwrap().to_digit(10).unwrap())
+        } else if s.starts_with("one") {
+            Some(1)
+        } else if s.starts_with("two") {
+            Some(2)
+        } else if s.starts_with("three") {
+            Some(3)
+        } else if s.starts_with("four") {
+            Some(4)
+        } else if s.starts_with("five") {
+            Some(5)
+        } else if s.starts_with("six") {
+            Some(6)
+        } else if s.starts_with("seven") {
+            Some(7)
+        } else if s.starts_with("eight") {
+            Some(8)
+        } else if s.starts_with("nine") {
+            Some(9)
+        } else {
+            None
+        };
+        s = &s[1..];
+
+        if let Some(n) = n {
+            if n1.is_none() {
+                n1 = Some(n);
+            }
+            n2 = Some(n)
+        }
+    }
+
+    if n1.is_none() {
+        return Err(anyhow!("no number in {:?}", line));
+    }
+
+    Ok(n1.unwrap() * 10 + n2.unwrap())
+}
+
+fn total(lines: &[String], part2: bool) -> Result<u32> {
+    let mut total = 0;
+    for line in lines {
+        let n = if part2 {
+            read_number_part2(line.as_str())?
+        } else {
+            read_number_part1(line.as_str())?
+        };
+        debug!("{} -> {}", line, n);
+        total += n;
+    }
+    Ok(total)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(total(input, false)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(total(input, true)?.into())
+    }
+}
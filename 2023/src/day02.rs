@@ -0,0 +1,116 @@
+use advent::common::{read_lines, Line, Template};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::Result;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Game {
+    number: u32,
+    draws: Vec<HashMap<String, u32>>,
+}
+
+impl Game {
+    fn is_possible(&self, totals: &HashMap<String, u32>) -> bool {
+        for draw in self.draws.iter() {
+            for (color, count) in draw.iter() {
+                let total = totals.get(color).unwrap_or(&0);
+                if *count > *total {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn power(&self) -> u32 {
+        let mut max_counts: HashMap<String, u32> = HashMap::new();
+        for draw in self.draws.iter() {
+            for (color, count) in draw.iter() {
+                let max_count = max_counts.get(color).unwrap_or(&0);
+                if *count > *max_count {
+                    max_counts.insert(color.into(), *count);
+                }
+            }
+        }
+        let mut p = 1;
+        for (_, count) in max_counts.iter() {
+            if *count > 0 {
+                p *= *count;
+            }
+        }
+        p
+    }
+}
+
+fn read_game(line: &Line, game: &Template, cubes: &Template) -> Result<Game> {
+    let c = game.captures(line)?;
+    let mut draws = Vec::new();
+    for draw_part in c.list::<String>("draws")? {
+        let mut draw: HashMap<String, u32> = HashMap::new();
+        for color_part in draw_part.split(", ") {
+            let c = cubes.captures(color_part)?;
+            draw.insert(c.str("color").into(), c.get("count")?);
+        }
+        draws.push(draw);
+    }
+
+    Ok(Game {
+        number: c.get("number")?,
+        draws,
+    })
+}
+
+fn read_input(path: &str) -> Result<Vec<Game>> {
+    let game = Template::new("Game {number}: {draws:; }");
+    let cubes = Template::new("{count} {color}");
+    let mut v = Vec::new();
+    for line in read_lines(path)? {
+        let game = read_game(&line, &game, &cubes)?;
+        v.push(game);
+    }
+    Ok(v)
+}
+
+fn totals() -> HashMap<String, u32> {
+    let mut totals: HashMap<String, u32> = HashMap::new();
+    totals.insert("red".into(), 12);
+    totals.insert("green".into(), 13);
+    totals.insert("blue".into(), 14);
+    totals
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, games: &Self::Input) -> Result<Answer> {
+        let totals = totals();
+        let mut ans: u64 = 0;
+        for game in games {
+            debug!("game: {:?}", game);
+            if game.is_possible(&totals) {
+                ans += game.number as u64;
+                debug!("possible!");
+            } else {
+                debug!("impossible!");
+            }
+        }
+        Ok(ans.into())
+    }
+
+    fn part2(&self, games: &Self::Input) -> Result<Answer> {
+        let mut ans: u64 = 0;
+        for game in games {
+            let power = game.power();
+            debug!("power: {}", power);
+            ans += power as u64;
+        }
+        Ok(ans.into())
+    }
+}
@@ -1,19 +1,7 @@
+use advent::common::read_lines;
+use advent::solution::{Answer, Solution};
 use anyhow::{Context, Result};
-use clap::Parser;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::option::Option;
-
-#[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Args {
-    #[arg(short, long)]
-    input: String,
-
-    #[arg(long)]
-    debug: bool,
-}
 
 // Where a number is, with the line and range [start, end).
 #[derive(Debug)]
@@ -32,7 +20,7 @@ struct PartLoc {
 }
 
 #[derive(Debug)]
-struct Board {
+pub struct Board {
     nums: Vec<NumLoc>,
     parts: HashMap<PartLoc, char>,
 }
@@ -53,7 +41,7 @@ impl Board {
                 '0'..='9' => {
                     if current_num.is_none() {
                         current_num = Some(NumLoc {
-                            row: row,
+                            row,
                             col_start: col as i32,
                             col_end: (col + 1) as i32,
                             num: 0,
@@ -71,7 +59,7 @@ impl Board {
                     if c != '.' {
                         self.parts.insert(
                             PartLoc {
-                                row: row,
+                                row,
                                 col: col as i32,
                             },
                             c,
@@ -101,7 +89,7 @@ impl Board {
     fn part1(&self) -> i32 {
         let mut ans = 0;
         for num in self.nums.iter() {
-            if self.is_num_near_part(&num) {
+            if self.is_num_near_part(num) {
                 ans += num.num;
             }
         }
@@ -150,40 +138,28 @@ impl Board {
     }
 }
 
-fn read_input(path: &str, _debug: bool) -> Result<Board> {
-    let file = File::open(path).with_context(|| format!("unable to open file {:?}", path))?;
-    let mut r = BufReader::new(file);
+fn read_input(path: &str) -> Result<Board> {
     let mut board = Board::new();
-    let mut row: i32 = 0;
-    loop {
-        let mut line = String::new();
-        let n = r.read_line(&mut line).unwrap();
-        let line = line.trim();
-
-        if line == "" {
-            if n == 0 {
-                break;
-            }
-            continue;
-        }
-
-        board.add_nums_and_parts(row, line)?;
-        row += 1;
+    for (row, line) in (0..).zip(read_lines(path)?) {
+        board.add_nums_and_parts(row, &line)?;
     }
     Ok(board)
 }
 
-fn process(args: &Args) -> Result<()> {
-    let board = read_input(&args.input, args.debug)?;
-    println!("ans1: {}", board.part1());
-    println!("ans2: {}", board.part2());
-    Ok(())
-}
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Board;
 
-fn main() {
-    let args = Args::parse();
-    match process(&args) {
-        Ok(_) => (),
-        Err(error) => panic!("{:?}", error),
-    };
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, board: &Self::Input) -> Result<Answer> {
+        Ok(board.part1().into())
+    }
+
+    fn part2(&self, board: &Self::Input) -> Result<Answer> {
+        Ok(board.part2().into())
+    }
 }
@@ -1,30 +1,18 @@
-use advent::common::{read_lines, StrIterator};
+use advent::common::{read_lines, Line, StrIterator};
+use advent::debug;
+use advent::solution::{Answer, Solution};
 use anyhow::{anyhow, Context, Result};
-use clap::Parser;
 use std::collections::{HashMap, HashSet};
-use std::option::Option;
-use std::str::FromStr;
-
-#[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Args {
-    #[arg(short, long)]
-    input: String,
-
-    #[arg(long)]
-    debug: bool,
-}
 
 #[derive(Debug)]
-struct Card {
+pub struct Card {
     winners: HashSet<i32>,
     chosen: Vec<i32>,
 }
 
-impl FromStr for Card {
-    type Err = anyhow::Error;
-
-    fn from_str(line: &str) -> Result<Card> {
+impl Card {
+    fn read(input: &Line) -> Result<Card> {
+        let line: &str = input;
         if !line.starts_with("Card ") {
             return Err(anyhow!("invalid line: {:?}", line));
         }
@@ -42,8 +30,8 @@ impl FromStr for Card {
         let (winners_str, chosen_str) = line.split_at(pipe_pos);
         let chosen_str = &chosen_str[1..];
 
-        let winners_vec = winners_str.split_whitespace().parse_all()?;
-        let chosen = chosen_str.split_whitespace().parse_all()?;
+        let winners_vec: Vec<i32> = input.tokens(winners_str.split_whitespace()).parse_all()?;
+        let chosen = input.tokens(chosen_str.split_whitespace()).parse_all()?;
 
         let mut winners = HashSet::new();
         for winner in winners_vec {
@@ -79,7 +67,7 @@ impl Card {
     }
 }
 
-fn score_cards(v: &Vec<Card>) -> i32 {
+fn score_cards(v: &[Card]) -> i32 {
     let mut score = 0;
     for c in v.iter() {
         score += c.score();
@@ -87,7 +75,7 @@ fn score_cards(v: &Vec<Card>) -> i32 {
     score
 }
 
-fn do_part2(v: &Vec<Card>, debug: bool) -> Result<i32> {
+fn do_part2(v: &[Card]) -> Result<i32> {
     let mut score = 0;
     let mut score_map: HashMap<usize, i32> = HashMap::new();
     for i in (0..v.len()).rev() {
@@ -105,28 +93,29 @@ fn do_part2(v: &Vec<Card>, debug: bool) -> Result<i32> {
         }
         score_map.insert(i, count);
         score += count;
-        if debug {
-            println!("{}: {}", i, count);
-        }
+        debug!("{}: {}", i, count);
     }
     Ok(score)
 }
 
-fn read_input(path: &str, _debug: bool) -> Result<Vec<Card>> {
-    Ok(read_lines(path)?.parse_all()?)
+fn read_input(path: &str) -> Result<Vec<Card>> {
+    read_lines(path)?.map(|line| Card::read(&line)).collect()
 }
 
-fn process(args: &Args) -> Result<()> {
-    let cards = read_input(&args.input, args.debug)?;
-    println!("ans1: {}", score_cards(&cards));
-    println!("ans2: {}", do_part2(&cards, args.debug)?);
-    Ok(())
-}
+pub struct Day04;
 
-fn main() {
-    let args = Args::parse();
-    match process(&args) {
-        Ok(_) => (),
-        Err(error) => panic!("{:?}", error),
-    };
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, cards: &Self::Input) -> Result<Answer> {
+        Ok(score_cards(cards).into())
+    }
+
+    fn part2(&self, cards: &Self::Input) -> Result<Answer> {
+        Ok(do_part2(cards)?.into())
+    }
 }
@@ -0,0 +1,241 @@
+use advent::common::{read_sections, Interval, IntervalSet, Line, StrIterator};
+use advent::solution::{Answer, Part, Solution, Strategy};
+use anyhow::{anyhow, Context, Result};
+
+#[derive(Debug)]
+struct MapRange {
+    src: Interval<i64>,
+    dst_offset: i64,
+}
+
+impl MapRange {
+    fn new(dst_start: i64, src_start: i64, len: i64) -> Self {
+        MapRange {
+            src: Interval::new(src_start, src_start + len),
+            dst_offset: dst_start - src_start,
+        }
+    }
+}
+
+impl MapRange {
+    fn read(line: &Line) -> Result<Self> {
+        let parts: Vec<i64> = line.tokens(line.split_whitespace()).parse_all()?;
+        if parts.len() != 3 {
+            let location = line.locate(line);
+            return Err(anyhow!(
+                "{}: expected 3 numbers, got {}\n{}",
+                location,
+                parts.len(),
+                location.snippet()
+            ));
+        }
+
+        Ok(MapRange::new(parts[0], parts[1], parts[2]))
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    ranges: Vec<MapRange>,
+}
+
+impl Map {
+    fn map_single(&self, src: i64) -> i64 {
+        match self.ranges.binary_search_by_key(&src, |r| r.src.start) {
+            Ok(i) => src + self.ranges[i].dst_offset,
+            Err(i) => {
+                if i == 0 || src >= self.ranges[i - 1].src.end {
+                    src
+                } else {
+                    src + self.ranges[i - 1].dst_offset
+                }
+            }
+        }
+    }
+
+    fn map_range(&self, src: &IntervalSet<i64>) -> IntervalSet<i64> {
+        let mut unmapped = src.clone();
+        let mut mapped = IntervalSet::new();
+        for range in self.ranges.iter() {
+            let range_set = range.src.into();
+            let hit = src.intersection(&range_set);
+            mapped = mapped.union(&hit.shifted(range.dst_offset));
+            unmapped = unmapped.difference(&range_set);
+        }
+        mapped.union(&unmapped)
+    }
+
+    fn verify(&self) -> Result<()> {
+        let mut previous = 0;
+        for range in self.ranges.iter() {
+            if range.src.start < previous {
+                return Err(anyhow!(
+                    "invalid range: {} < {} in {:?}",
+                    range.src.start,
+                    previous,
+                    range
+                ));
+            }
+            previous = range.src.end;
+        }
+        Ok(())
+    }
+
+    fn read(lines: &[Line]) -> Result<Self> {
+        let mut map = Map { ranges: Vec::new() };
+        for line in lines {
+            map.ranges.push(MapRange::read(line)?);
+        }
+        map.ranges.sort_by_key(|r| r.src.start);
+        map.verify()?;
+        Ok(map)
+    }
+
+    fn read_with_name(section: &[Line], name: &str) -> Result<Self> {
+        let (first, rest) = section
+            .split_first()
+            .with_context(|| format!("missing map {:?}", name))?;
+        let line = first.text.trim();
+        if line != name {
+            return Err(anyhow!(
+                "expected {:?}, got {:?} on line {}",
+                name,
+                line,
+                first.number
+            ));
+        }
+
+        Self::read(rest)
+    }
+}
+
+// The headers of each map, in the order they're applied.
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil map:",
+    "soil-to-fertilizer map:",
+    "fertilizer-to-water map:",
+    "water-to-light map:",
+    "light-to-temperature map:",
+    "temperature-to-humidity map:",
+    "humidity-to-location map:",
+];
+
+#[derive(Debug)]
+pub struct Input {
+    seeds: Vec<i64>,
+    maps: Vec<Map>,
+}
+
+fn read_seeds(section: &[Line]) -> Result<Vec<i64>> {
+    let (first, rest) = section
+        .split_first()
+        .context("unexpected eof at start of file")?;
+    let line = first.text.trim();
+    if !line.starts_with("seeds: ") {
+        return Err(anyhow!("expected \"seeds: \", got {:?}", line));
+    }
+    let line = &line[7..];
+    let seeds = first.tokens(line.split_whitespace()).parse_all()?;
+
+    if let Some(extra) = rest.first() {
+        return Err(anyhow!(
+            "expected blank line after seeds. got {:?} on line {}",
+            extra.text,
+            extra.number
+        ));
+    }
+
+    Ok(seeds)
+}
+
+impl Input {
+    fn location_for_seed(&self, seed: i64) -> i64 {
+        let mut n = seed;
+        for m in self.maps.iter() {
+            n = m.map_single(n);
+        }
+        n
+    }
+
+    fn part1(&self) -> i64 {
+        let mut loc = self.location_for_seed(self.seeds[0]);
+        for seed in self.seeds.iter() {
+            let new_loc = self.location_for_seed(*seed);
+            if new_loc < loc {
+                loc = new_loc;
+            }
+        }
+        loc
+    }
+
+    // Maps every seed in `seeds` at once, a range at a time.
+    fn lowest_location(&self, mut seeds: IntervalSet<i64>) -> Result<i64> {
+        for m in self.maps.iter() {
+            seeds = m.map_range(&seeds);
+        }
+        seeds.min().context("no seeds")
+    }
+
+    // Part 1 again, with each seed as a range of one.
+    fn part1_by_ranges(&self) -> Result<i64> {
+        self.lowest_location(
+            self.seeds
+                .iter()
+                .map(|&seed| Interval::new(seed, seed + 1))
+                .collect(),
+        )
+    }
+
+    fn part2(&self) -> Result<i64> {
+        let seeds: IntervalSet<i64> = self
+            .seeds
+            .chunks(2)
+            .map(|pair| match pair {
+                [start, len] => Ok(Interval::new(*start, start + len)),
+                _ => Err(anyhow!("seeds are not in pairs")),
+            })
+            .collect::<Result<_>>()?;
+        self.lowest_location(seeds)
+    }
+
+    fn read(path: &str) -> Result<Self> {
+        let mut sections = read_sections(path)?.into_iter();
+        let seeds = read_seeds(&sections.next().unwrap_or_default())?;
+        let mut maps = Vec::new();
+        for name in MAP_NAMES {
+            let section = sections
+                .next()
+                .with_context(|| format!("unexpected eof for map {:?}", name))?;
+            maps.push(Map::read_with_name(&section, name)?);
+        }
+        Ok(Input { seeds, maps })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Input;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        Input::read(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.part2()?.into())
+    }
+
+    fn strategies(&self, part: Part) -> Vec<Strategy<Self::Input>> {
+        match part {
+            Part::One => vec![Strategy {
+                name: "ranges",
+                solve: |input| Ok(input.part1_by_ranges()?.into()),
+            }],
+            Part::Two => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use advent::common::{read_lines, Line, StrIterator};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{bail, Context, Result};
+use std::iter::zip;
+
+fn compute_charge(time: i64, distance: i64) -> i64 {
+    // distance = (time - charge) * charge
+    // 0 = -charge^2 + charge*time + -distance
+    let t = time as f64;
+    let d = distance as f64 + 0.00001;
+    let charge1 = (-t + (t * t - 4.0 * d).sqrt()) / -2.0;
+    let charge2 = (-t - (t * t - 4.0 * d).sqrt()) / -2.0;
+    let minimum = charge1.ceil() as i64;
+    let maximum = charge2.floor() as i64;
+    let score = (maximum - minimum) + 1;
+    debug!(
+        "time: {}, distance: {}, charge1: {}, charge2: {}, min: {}, max: {}, score: {}",
+        time, distance, charge1, charge2, minimum, maximum, score
+    );
+    score
+}
+
+fn part1(times: Vec<i64>, distances: Vec<i64>) -> i64 {
+    let mut score = 1;
+    let zipped = zip(times, distances);
+    for (time, distance) in zipped {
+        score *= compute_charge(time, distance);
+    }
+    score
+}
+
+#[derive(Debug)]
+pub struct Races {
+    times: Vec<i64>,
+    distances: Vec<i64>,
+}
+
+fn read_numbers(line: Option<Line>, prefix: &str) -> Result<Vec<i64>> {
+    let line = line.with_context(|| format!("missing {:?} line", prefix))?;
+    let rest = line
+        .strip_prefix(prefix)
+        .with_context(|| format!("expected {:?}, got {:?}", prefix, line))?;
+    Ok(line.tokens(rest.split_whitespace()).parse_all()?)
+}
+
+// Reads the numbers on a line as a single number, ignoring the spaces.
+fn join_numbers(v: &[i64]) -> Result<i64> {
+    let s: String = v.iter().map(|n| n.to_string()).collect();
+    Ok(s.parse()?)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        let mut lines = read_lines(path)?;
+        let times = read_numbers(lines.next(), "Time:")?;
+        let distances = read_numbers(lines.next(), "Distance:")?;
+        if times.len() != distances.len() {
+            bail!("mismatched times {:?} and distances {:?}", times, distances);
+        }
+        Ok(Races { times, distances })
+    }
+
+    fn part1(&self, races: &Self::Input) -> Result<Answer> {
+        Ok(part1(races.times.clone(), races.distances.clone()).into())
+    }
+
+    fn part2(&self, races: &Self::Input) -> Result<Answer> {
+        let time = join_numbers(&races.times)?;
+        let distance = join_numbers(&races.distances)?;
+        Ok(compute_charge(time, distance).into())
+    }
+}
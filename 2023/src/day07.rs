@@ -1,22 +1,8 @@
+use advent::common::read_lines;
+use advent::debug;
+use advent::solution::{Answer, Solution};
 use anyhow::{anyhow, Context, Result};
-use clap::Parser;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::option::Option;
-
-#[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Args {
-    #[arg(short, long)]
-    input: String,
-
-    #[arg(long)]
-    part2: bool,
-
-    #[arg(long)]
-    debug: bool,
-}
 
 #[derive(Copy, Clone, Debug)]
 enum HandType {
@@ -121,54 +107,44 @@ impl Record {
     fn from_str(line: &str, part2: bool) -> Result<Self> {
         Ok(Record {
             hand: Hand::from_str(&line[..5], part2)?,
-            bid: (&line[6..])
+            bid: line[6..]
                 .parse()
                 .context(format!("invalid bid: {}", line))?,
         })
     }
 }
 
-fn read_input(path: &str, part2: bool, _debug: bool) -> Result<Vec<Record>> {
-    let file = File::open(path).with_context(|| format!("unable to open file {:?}", path))?;
-    let mut r = BufReader::new(file);
+fn total_winnings(lines: &[String], part2: bool) -> Result<i32> {
     let mut records = Vec::new();
-    loop {
-        let mut line = String::new();
-        let n = r.read_line(&mut line).unwrap();
-        let line = line.trim();
-
-        if line == "" {
-            if n == 0 {
-                break;
-            }
-            continue;
-        }
-
+    for line in lines {
         records.push(Record::from_str(line, part2)?);
     }
-    Ok(records)
-}
-
-fn process(args: &Args) -> Result<()> {
-    let mut records = read_input(&args.input, args.part2, args.debug)?;
 
     records.sort_by_key(|r: &Record| (r.hand.typ as i32, r.hand.values));
 
     let mut ans = 0;
     for (i, record) in records.iter().enumerate() {
-        println!("{:?}", record);
+        debug!("{:?}", record);
         let rank = i as i32 + 1;
         ans += record.bid * rank;
     }
-
-    println!("ans1: {}", ans);
-    Ok(())
+    Ok(ans)
 }
 
-fn main() {
-    let args = Args::parse();
-    match process(&args) {
-        Ok(_) => (),
-        Err(error) => panic!("{:?}", error),
-    };
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<String>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        Ok(read_lines(path)?.map(|line| line.text).collect())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Result<Answer> {
+        Ok(total_winnings(lines, false)?.into())
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Result<Answer> {
+        Ok(total_winnings(lines, true)?.into())
+    }
 }
@@ -0,0 +1,165 @@
+use advent::common::{fast_forward, read_lines, CycleStrategy, Template};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, Context, Result};
+use num::integer::lcm;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+enum Direction {
+    Left = 0,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    directions: Vec<Direction>,
+    map: HashMap<String, (String, String)>,
+}
+
+impl Input {
+    fn read(path: &str) -> Result<Self> {
+        let mut directions: Option<String> = None;
+        let mut map = HashMap::new();
+        let node = Template::new("{start} = ({left}, {right})");
+        for line in read_lines(path)? {
+            if directions.is_none() {
+                directions = Some(line.text);
+            } else {
+                let c = node.captures(&line)?;
+                map.insert(
+                    c.str("start").to_owned(),
+                    (c.str("left").to_owned(), c.str("right").to_owned()),
+                );
+            }
+        }
+        let directions = directions.context("missing directions")?;
+        let directions: Result<Vec<Direction>> = directions
+            .chars()
+            .map(|c| match c {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                _ => Err(anyhow!("invalid direction: {}", c)),
+            })
+            .collect();
+        let directions = directions?;
+        if directions.is_empty() {
+            return Err(anyhow!("missing directions"));
+        }
+        for (left, right) in map.values() {
+            for next in [left, right] {
+                if !map.contains_key(next) {
+                    return Err(anyhow!("unknown position: {}", next));
+                }
+            }
+        }
+
+        Ok(Input { directions, map })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let mut total = 0;
+        let mut i = 0;
+        let mut current: String = "AAA".to_owned();
+        while current != "ZZZ" {
+            let (left, right) = self
+                .map
+                .get(&current)
+                .context(format!("unknown position: {}", current))?;
+
+            current = match self.directions[i] {
+                Direction::Left => left.clone(),
+                Direction::Right => right.clone(),
+            };
+
+            total += 1;
+            i = (i + 1) % self.directions.len();
+        }
+        Ok(total)
+    }
+
+    // The position reached by following the i-th direction from `current`.
+    fn follow(&self, current: &str, i: usize) -> &str {
+        let (left, right) = self.map.get(current).expect("positions checked in read");
+        match self.directions[i] {
+            Direction::Left => left,
+            Direction::Right => right,
+        }
+    }
+
+    fn find_cycle(&self, start: String) -> Result<u64> {
+        // The state is (direction index, location).
+        let next = |(i, current): &(usize, String)| {
+            (
+                (i + 1) % self.directions.len(),
+                self.follow(current, *i).to_owned(),
+            )
+        };
+        let cycle = fast_forward((0, start.clone()), u64::MAX, next, CycleStrategy::HashMap)
+            .cycle
+            .context("no cycle found")?;
+
+        // The step and name of every destination before the path repeats.
+        let mut dests: Vec<(u64, String)> = Vec::new();
+        let mut state = (0, start);
+        for step in 0..(cycle.prefix + cycle.period) {
+            if state.1.ends_with('Z') {
+                dests.push((step, state.1.clone()));
+            }
+            state = next(&state);
+        }
+
+        match dests.as_slice() {
+            [] => Err(anyhow!("loop without a dest!")),
+            [(dest_step, dest)] => {
+                debug!(
+                    "loop from {} to {} with dest {} at {}",
+                    cycle.prefix + cycle.period,
+                    cycle.prefix,
+                    dest,
+                    dest_step
+                );
+                if *dest_step != cycle.period {
+                    return Err(anyhow!("cycle would have non-zero offset"));
+                }
+                Ok(*dest_step)
+            }
+            [(s, n), (step, current), ..] => Err(anyhow!(
+                "at {}, {}, but already saw {}, {}",
+                step,
+                current,
+                s,
+                n
+            )),
+        }
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let mut ans: u64 = 1;
+        for node in self.map.keys() {
+            if node.ends_with("A") {
+                let cycle = self.find_cycle(node.clone())?;
+                ans = lcm(ans, cycle);
+            }
+        }
+        Ok(ans)
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Input;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        Input::read(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.part1()?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.part2()?.into())
+    }
+}
@@ -0,0 +1,63 @@
+use advent::common::{read_lines, StrIterator};
+use advent::solution::{Answer, Solution};
+use advent::trace;
+use anyhow::{anyhow, Result};
+
+fn next_value(v: &Vec<i64>, part2: bool) -> Result<i64> {
+    trace!("{:?}", v);
+    if v.is_empty() {
+        return Err(anyhow!("empty vec"));
+    }
+
+    let mut next = Vec::new();
+    let mut all_zeroes = true;
+    for (i, n) in v.iter().enumerate() {
+        if *n != 0 {
+            all_zeroes = false;
+        }
+        if i > 0 {
+            next.push(n - v[i - 1]);
+        }
+    }
+    if all_zeroes {
+        return Ok(0);
+    }
+
+    let d = next_value(&next, part2)?;
+    trace!("delta = {}", d);
+    Ok(if part2 {
+        v.first().expect("vec should not be empty") - d
+    } else {
+        v.last().expect("vec should not be empty") + d
+    })
+}
+
+fn extrapolate(input: &[Vec<i64>], part2: bool) -> Result<i64> {
+    let mut total = 0;
+    for nums in input {
+        total += next_value(nums, part2)?;
+    }
+    Ok(total)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        let mut v = Vec::new();
+        for line in read_lines(path)? {
+            v.push(line.tokens(line.split_whitespace()).parse_all()?);
+        }
+        Ok(v)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(extrapolate(input, false)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(extrapolate(input, true)?.into())
+    }
+}
@@ -1,19 +1,9 @@
-use advent::common::read_lines;
-use anyhow::{anyhow, Result};
-use clap::Parser;
+use advent::common::{read_grid_with_markers, Array2D, CommonError};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
 use std::collections::VecDeque;
-use std::option::Option;
-
-#[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Args {
-    #[arg(short, long)]
-    input: String,
-
-    #[arg(long)]
-    debug: bool,
-}
 
 #[derive(Debug)]
 struct Node {
@@ -61,11 +51,7 @@ impl Node {
 
     fn d(&self) -> Option<i64> {
         if let Some(d1) = self.d1 {
-            if let Some(d2) = self.d2 {
-                Some(d1.min(d2))
-            } else {
-                None
-            }
+            self.d2.map(|d2| d1.min(d2))
         } else {
             None
         }
@@ -77,48 +63,35 @@ impl Node {
 }
 
 #[derive(Debug)]
-struct Input {
+pub struct Input {
     start: (usize, usize),
-    map: Vec<Vec<Node>>,
+    map: Array2D<Node>,
 }
 
 impl Input {
-    fn read(path: &str, _debug: bool) -> Result<Self> {
-        let mut start = (0, 0);
-        let mut map = Vec::new();
-        for line in read_lines(path)? {
-            map.push(line.chars().map(Node::from_char).collect());
-
-            if let Some(s) = line.find('S') {
-                start = ((map.len() - 1), s);
+    fn read(path: &str) -> Result<Self> {
+        let (map, markers) = read_grid_with_markers(path, &["start"], |c, (r, col), markers| {
+            if c == 'S' {
+                markers.mark("start", r, col);
             }
-        }
-        let mut input = Input { start, map };
+            Ok::<Node, CommonError>(Node::from_char(c))
+        })?;
+        let mut input = Input {
+            start: markers["start"],
+            map,
+        };
         input.patch_start();
         Ok(input)
     }
 
-    fn print(&self) {
-        for row in self.map.iter() {
-            let line: String = row.iter().map(Node::to_char).collect();
-            println!("{}", line);
-        }
-    }
-
     // row-major order
     fn get(&self, row_col: (usize, usize)) -> Option<&Node> {
-        match self.map.get(row_col.0) {
-            Some(v) => v.get(row_col.1),
-            None => None,
-        }
+        self.map.get(row_col.0, row_col.1)
     }
 
     // row-major order
     fn get_mut(&mut self, row_col: (usize, usize)) -> Option<&mut Node> {
-        match self.map.get_mut(row_col.0) {
-            Some(v) => v.get_mut(row_col.1),
-            None => None,
-        }
+        self.map.get_mut(row_col.0, row_col.1)
     }
 
     fn find_starts(&self) -> Result<((usize, usize), (usize, usize))> {
@@ -131,7 +104,7 @@ impl Input {
                 possible.push((self.start.0 - 1, self.start.1));
             }
         }
-        if self.start.0 < self.map.len() - 1 {
+        if self.start.0 < self.map.rows() - 1 {
             let s = self
                 .get((self.start.0 + 1, self.start.1))
                 .expect("in bounds");
@@ -147,7 +120,7 @@ impl Input {
                 possible.push((self.start.0, self.start.1 - 1));
             }
         }
-        if self.start.1 < self.map.first().expect("not empty").len() - 1 {
+        if self.start.1 < self.map.columns() - 1 {
             let e = self
                 .get((self.start.0, self.start.1 + 1))
                 .expect("in bounds");
@@ -170,7 +143,7 @@ impl Input {
         } else {
             false
         };
-        let s = if self.start.0 < self.map.len() - 1 {
+        let s = if self.start.0 < self.map.rows() - 1 {
             let s = self
                 .get((self.start.0 + 1, self.start.1))
                 .expect("in bounds");
@@ -186,7 +159,7 @@ impl Input {
         } else {
             false
         };
-        let e = if self.start.1 < self.map.first().expect("not empty").len() - 1 {
+        let e = if self.start.1 < self.map.columns() - 1 {
             let e = self
                 .get((self.start.0, self.start.1 + 1))
                 .expect("in bounds");
@@ -266,34 +239,34 @@ impl Input {
         }
     }
 
-    fn part1(&mut self, debug: bool) -> Result<i64> {
+    // Fills in the distance along the loop for each node, starting in both directions.
+    fn trace_loop(&mut self) -> Result<()> {
         let (s1, s2) = self.find_starts()?;
 
         self.propagate_distance(s1, false);
         self.propagate_distance(s2, true);
 
-        if debug {
-            self.print();
-        }
+        debug!("{}", self.map.render_with(Node::to_char));
+        Ok(())
+    }
 
+    fn part1(&self) -> Result<i64> {
         let mut ans: Option<i64> = None;
-        for row in self.map.iter() {
-            for node in row {
-                if let Some(d) = node.d() {
-                    ans = Some(match ans {
-                        Some(d3) => d3.max(d),
-                        None => d,
-                    });
-                }
+        for (_, _, node) in self.map.enumerate() {
+            if let Some(d) = node.d() {
+                ans = Some(match ans {
+                    Some(d3) => d3.max(d),
+                    None => d,
+                });
             }
         }
 
-        Ok(ans.expect("an answer"))
+        ans.context("no node is in the loop")
     }
 
-    fn part2(&mut self, _debug: bool) -> usize {
+    fn part2(&self) -> usize {
         let mut total: usize = 0;
-        for row in self.map.iter() {
+        for row in self.map.iter_rows() {
             let mut inside = false;
             let mut above_border = false;
             let mut below_border = false;
@@ -352,21 +325,23 @@ impl Input {
     }
 }
 
-fn process(args: &Args) -> Result<()> {
-    let mut input = Input::read(&args.input, args.debug)?;
-    if args.debug {
-        input.print();
-        println!("");
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Input;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        let mut input = Input::read(path)?;
+        debug!("{}", input.map.render_with(Node::to_char));
+        input.trace_loop()?;
+        Ok(input)
     }
-    println!("ans1: {}", input.part1(args.debug)?);
-    println!("ans2: {}", input.part2(args.debug));
-    Ok(())
-}
 
-fn main() {
-    let args = Args::parse();
-    match process(&args) {
-        Ok(_) => (),
-        Err(error) => panic!("{:?}", error),
-    };
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.part1()?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.part2().into())
+    }
 }
@@ -0,0 +1,125 @@
+use advent::common::read_lines;
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::Result;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Input {
+    rows: usize,
+    columns: usize,
+    galaxies: Vec<(usize, usize)>,
+}
+
+impl Input {
+    fn read(path: &str) -> Result<Self> {
+        let mut rows: usize = 0;
+        let mut columns: usize = 0;
+        let mut galaxies: Vec<(usize, usize)> = Vec::new();
+
+        for line in read_lines(path)? {
+            for (i, c) in line.chars().enumerate() {
+                columns = columns.max(i + 1);
+                if c == '#' {
+                    galaxies.push((rows, i));
+                }
+            }
+            rows += 1;
+        }
+
+        Ok(Input {
+            rows,
+            columns,
+            galaxies,
+        })
+    }
+
+    // Returns a copy of the universe where every empty row and column is replaced by `expansion` of them.
+    fn expand(&self, expansion: usize) -> Self {
+        let expansion = expansion - 1;
+        let mut rows = self.rows;
+        let mut columns = self.columns;
+        let mut galaxies = self.galaxies.clone();
+        let rows_seen: HashSet<usize> = self.galaxies.iter().map(|g| g.0).collect();
+        let columns_seen: HashSet<usize> = self.galaxies.iter().map(|g| g.1).collect();
+
+        for row in (0..self.rows).rev() {
+            if !rows_seen.contains(&row) {
+                for galaxy in galaxies.iter_mut() {
+                    if galaxy.0 > row {
+                        galaxy.0 += expansion;
+                    }
+                }
+                rows += expansion;
+            }
+        }
+        for col in (0..self.columns).rev() {
+            if !columns_seen.contains(&col) {
+                for galaxy in galaxies.iter_mut() {
+                    if galaxy.1 > col {
+                        galaxy.1 += expansion;
+                    }
+                }
+                columns += expansion;
+            }
+        }
+
+        Input {
+            rows,
+            columns,
+            galaxies,
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut s = String::new();
+        for r in 0..self.rows {
+            for c in 0..self.columns {
+                let is_galaxy = self.galaxies.binary_search(&(r, c)).is_ok();
+                s.push(if is_galaxy { '#' } else { '.' });
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    fn compute(&self) -> Result<usize> {
+        // Add up the manhattan distance between every pair.
+        let mut total: usize = 0;
+        for (i, gi) in self.galaxies.iter().enumerate() {
+            for (j, gj) in self.galaxies[..i].iter().enumerate() {
+                let dy = gi.0.abs_diff(gj.0);
+                let dx = gi.1.abs_diff(gj.1);
+                let d = dx + dy;
+                debug!("d({}, {}) = {}", i, j, d);
+                total += d;
+            }
+        }
+
+        Ok(total)
+    }
+}
+
+fn sum_of_distances(input: &Input, expansion: usize) -> Result<usize> {
+    let input = input.expand(expansion);
+    debug!("\n{}", input.render());
+    input.compute()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Input;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        Input::read(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_of_distances(input, 2)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_of_distances(input, 1000000)?.into())
+    }
+}
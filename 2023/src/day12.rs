@@ -0,0 +1,198 @@
+use advent::common::{read_lines, StrIterator};
+use advent::solution::{Answer, Solution};
+use advent::{debug, trace};
+use anyhow::{Context, Result};
+use indicatif::ProgressBar;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Record {
+    text: String,
+    counts: Vec<usize>,
+}
+
+impl Record {
+    fn expand(&self) -> Self {
+        let t = self.text.as_str();
+        let text = [t, t, t, t, t].join("?");
+        let mut counts = Vec::new();
+        for _ in 0..5 {
+            for &n in self.counts.iter() {
+                counts.push(n);
+            }
+        }
+        Record { text, counts }
+    }
+}
+
+fn read_input(path: &str) -> Result<Vec<Record>> {
+    let mut v = Vec::new();
+
+    for line in read_lines(path)? {
+        let space = line.find(' ').context(format!("invalid line: {}", line))?;
+        let (text, count_text) = line.split_at(space);
+        let text = text.to_owned();
+        let count_text = &count_text[1..];
+        let counts = line.tokens(count_text.split(',')).parse_all()?;
+        v.push(Record { text, counts });
+    }
+
+    Ok(v)
+}
+
+// Returns true if tail matches (.|?)*.
+fn parse_tail(line: &[char]) -> bool {
+    trace!("parse_tail({:?})", line.iter().collect::<String>());
+
+    for &c in line {
+        if c != '.' && c != '?' {
+            trace!("<- false (invalid tail)");
+            return false;
+        }
+    }
+    trace!("<- true");
+    true
+}
+
+// Returns the number of ways the line can match starting with the first number.
+fn parse_pounds(
+    line: &[char],
+    nums: &[usize],
+    cache: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    trace!(
+        "parse_pounds({:?}, {:?})",
+        line.iter().collect::<String>(),
+        nums
+    );
+
+    // This should never get called when no numbers are expected.
+    if nums.is_empty() {
+        panic!("got a line with no numbers");
+    }
+
+    // As an optimization, fail now if the rest isn't long enough.
+    let mut min_len = 0usize;
+    for &n in nums.iter() {
+        min_len += n;
+    }
+    min_len += nums.len() - 1;
+    if line.len() < min_len {
+        trace!("<- 0 (line.len() = {} < min_len = {})", line.len(), min_len);
+        return 0;
+    }
+
+    // Check the first number.
+    let &n = nums.first().expect("len > 0");
+    for &c in &line[..n] {
+        if c != '#' && c != '?' {
+            trace!("<- 0 ({} is not a pound)", c);
+            return 0;
+        }
+    }
+    // If that's the only number, then check we only have dots after that.
+    if nums.len() == 1 {
+        let ans = if parse_tail(&line[n..]) { 1 } else { 0 };
+        trace!("<- {} (parse_pounds)", ans);
+        return ans;
+    }
+
+    // Check that the number is followed by a dot.
+    if line[n] != '.' && line[n] != '?' {
+        trace!("<- 0 (no trailing dot)");
+        return 0;
+    }
+
+    // Parse the rest.
+    let n = n + 1;
+    let ans = parse_dots(&line[n..], &nums[1..], cache);
+    trace!("<- {} (parse_pounds)", ans);
+    ans
+}
+
+fn parse_dots(line: &[char], nums: &[usize], cache: &mut HashMap<(usize, usize), usize>) -> usize {
+    if let Some(&ans) = cache.get(&(line.len(), nums.len())) {
+        return ans;
+    }
+
+    trace!(
+        "parse_dots({:?}, {:?})",
+        line.iter().collect::<String>(),
+        nums
+    );
+    if nums.is_empty() {
+        panic!("got a line with no numbers");
+    }
+    if line.is_empty() {
+        trace!("<- 0 (empty line)");
+        return 0;
+    }
+
+    // As an optimization, fail now if the rest isn't long enough.
+    let mut min_len = 0usize;
+    for &n in nums.iter() {
+        min_len += n;
+    }
+    min_len += nums.len() - 1;
+    if line.len() < min_len {
+        trace!("<- 0 (line.len() = {} < min_len = {})", line.len(), min_len);
+        return 0;
+    }
+
+    let ans = match line[0] {
+        '.' => parse_dots(&line[1..], nums, cache),
+        '#' => parse_pounds(line, nums, cache),
+        '?' => {
+            let n1 = parse_dots(&line[1..], nums, cache);
+            let n2 = parse_pounds(line, nums, cache);
+            n1 + n2
+        }
+        _ => panic!("invalid char: {}", line[0]),
+    };
+    trace!("<- {} (parse_dots)", ans);
+    cache.insert((line.len(), nums.len()), ans);
+    ans
+}
+
+fn process_record(record: &Record) -> usize {
+    let line = record.text.chars().collect_vec();
+    let nums = record.counts.clone();
+    let mut cache = HashMap::new();
+    let n = parse_dots(&line[..], &nums[..], &mut cache);
+    debug!("{:?} -> {}", record, n);
+    n
+}
+
+fn count_arrangements(input: &[Record], expand: bool) -> usize {
+    let progress = ProgressBar::new(input.len() as u64);
+    let mut total = 0usize;
+    for record in input.iter() {
+        total += if expand {
+            process_record(&record.expand())
+        } else {
+            process_record(record)
+        };
+        progress.inc(1);
+    }
+    progress.finish();
+    total
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(count_arrangements(input, false).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(count_arrangements(input, true).into())
+    }
+}
@@ -0,0 +1,87 @@
+use advent::common::{parse_grid, read_sections, Array2D};
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, Result};
+use indicatif::ProgressBar;
+use std::iter::zip;
+
+// Returns the number of rows above a horizontal line of reflection.
+// The reflection must be off by exactly `smudges` cells.
+fn find_mirror(grid: &Array2D<bool>, smudges: usize) -> Option<usize> {
+    (1..grid.rows()).find(|&split| {
+        let mut wrong = 0;
+        for (a, b) in (0..split).rev().zip(split..grid.rows()) {
+            wrong += zip(grid.row(a), grid.row(b))
+                .filter(|(x, y)| x != y)
+                .count();
+            if wrong > smudges {
+                return false;
+            }
+        }
+        wrong == smudges
+    })
+}
+
+fn summarize_grid(grid: &Array2D<bool>, smudges: usize) -> Result<usize> {
+    if let Some(n) = find_mirror(grid, smudges) {
+        return Ok(100 * n);
+    }
+    let mut transposed = grid.clone();
+    transposed.transpose();
+    find_mirror(&transposed, smudges).ok_or_else(|| anyhow!("no answer!"))
+}
+
+fn read_input(path: &str) -> Result<Vec<Array2D<bool>>> {
+    let mut grids = Vec::new();
+    for section in read_sections(path)? {
+        let lines = section.into_iter().map(|line| line.trimmed());
+        grids.push(parse_grid(lines, |c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(format!("invalid char {}", c)),
+        })?);
+    }
+    Ok(grids)
+}
+
+fn summarize(input: &[Array2D<bool>], smudges: usize) -> Result<usize> {
+    let progress = ProgressBar::new(input.len() as u64);
+    let mut total = 0usize;
+    for grid in input.iter() {
+        debug!("{}", grid.render_with(|&b| if b { '#' } else { '.' }));
+        total += summarize_grid(grid, smudges)?;
+        progress.inc(1);
+    }
+    progress.finish();
+    Ok(total)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Array2D<bool>>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(summarize(input, 0)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(summarize(input, 1)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day13.parse("data/13/sample.txt").unwrap();
+        assert_eq!(Day13.part1(&input).unwrap(), 405.into());
+        assert_eq!(Day13.part2(&input).unwrap(), 400.into());
+    }
+}
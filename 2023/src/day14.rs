@@ -0,0 +1,171 @@
+use advent::common::animate::{self, Frame};
+use advent::common::render::{self, Color, GifWriter, Image};
+use advent::common::{fast_forward, read_grid, Array2D, CycleStrategy};
+use advent::debug;
+use advent::solution::{record_counter, Answer, Solution};
+use anyhow::{bail, Result};
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Input {
+    map: Array2D<char>,
+}
+
+impl Input {
+    // Returns the total load on the north support beams.
+    fn score(&self) -> usize {
+        let rows = self.map.rows();
+        self.map
+            .enumerate()
+            .filter(|(_, _, &c)| c == 'O')
+            .map(|(i, _, _)| rows - i)
+            .sum()
+    }
+
+    // Rolls every round rock as far north as it will go.
+    fn tilt_north(&mut self) {
+        let mut limit: Vec<usize> = vec![0; self.map.columns()];
+        for i in 0..self.map.rows() {
+            for (j, limit) in limit.iter_mut().enumerate() {
+                match self.map[(i, j)] {
+                    '#' => *limit = i + 1,
+                    'O' => {
+                        self.map[(i, j)] = '.';
+                        self.map[(*limit, j)] = 'O';
+                        *limit += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // Tilts north, west, south, and then east.
+    // Rotating clockwise after each tilt brings the next edge to the north.
+    fn cycle(&mut self) {
+        for _ in 0..4 {
+            self.tilt_north();
+            self.map.rotate_clockwise();
+        }
+    }
+}
+
+fn read_input(path: &str) -> Result<Input> {
+    let map = read_grid(path, |c| match c {
+        '.' | '#' | 'O' => Ok(c),
+        _ => Err(format!("invalid char {}", c)),
+    })?;
+    if map.rows() == 0 {
+        bail!("input is empty");
+    }
+    Ok(Input { map })
+}
+
+fn tilt(input: &Input) -> usize {
+    let mut input = input.clone();
+    input.tilt_north();
+    input.score()
+}
+
+// The number of spin cycles in part 2.
+const SPINS: u64 = 1_000_000_000;
+
+// Keeps the animation of the spin cycles from getting too big, in case they never repeat.
+const MAX_FRAMES: u64 = 500;
+
+fn rock_color(c: &char) -> Color {
+    match c {
+        'O' => Color::rgb(230, 200, 120),
+        '#' => Color::rgb(90, 90, 100),
+        _ => Color::rgb(20, 20, 30),
+    }
+}
+
+// Animates the first `spins` spin cycles, one frame each.
+fn write_spins(input: &Input, spins: u64, path: &Path) -> Result<()> {
+    let frame = |input: &Input| Image::render(&input.map, 4, rock_color);
+    let mut input = input.clone();
+    let first = frame(&input);
+    let mut gif = GifWriter::create(
+        path,
+        first.width(),
+        first.height(),
+        Duration::from_millis(100),
+    )?;
+    gif.add_frame(&first)?;
+    for _ in 0..spins.min(MAX_FRAMES) {
+        input.cycle();
+        gif.add_frame(&frame(&input))?;
+    }
+    debug!("wrote {}", path.display());
+    Ok(())
+}
+
+// Plays the first `spins` spin cycles in the terminal.
+fn play_spins(input: &Input, spins: u64) -> Result<()> {
+    let mut state = (input.clone(), 0);
+    animate::simulate(
+        &mut state,
+        &[('O', rock_color(&'O')), ('#', rock_color(&'#'))],
+        |(input, spun)| {
+            if *spun == spins.min(MAX_FRAMES) {
+                return false;
+            }
+            input.cycle();
+            *spun += 1;
+            true
+        },
+        |(input, _)| Frame::new(input.map.render_with(|&c| c)),
+    )?;
+    Ok(())
+}
+
+fn spin(input: &Input) -> Result<usize> {
+    let result = fast_forward(
+        input.clone(),
+        SPINS,
+        |input| {
+            let mut input = input.clone();
+            input.cycle();
+            input
+        },
+        CycleStrategy::HashMap,
+    );
+    if let Some(cycle) = &result.cycle {
+        record_counter("cycle_prefix", cycle.prefix);
+        record_counter("cycle_length", cycle.period);
+        debug!("repeats every {} after {}", cycle.period, cycle.prefix);
+    }
+    debug!("\n{}", result.state.map.render_with(|&c| c));
+    // Once around the cycle is enough to see everything.
+    let spins = result
+        .cycle
+        .as_ref()
+        .map_or(MAX_FRAMES, |cycle| cycle.prefix + cycle.period);
+    if let Some(path) = render::output("2023-14-spin.gif") {
+        write_spins(input, spins, &path)?;
+    }
+    if animate::animation().is_some() {
+        play_spins(input, spins)?;
+    }
+    Ok(result.state.score())
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Input;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(tilt(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(spin(input)?.into())
+    }
+}
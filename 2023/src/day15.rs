@@ -1,18 +1,8 @@
-use anyhow::{anyhow, Context, Result};
-use clap::Parser;
-use std::fs::File;
-use std::io::Read;
-use std::option::Option;
-
-#[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Args {
-    #[arg(short, long)]
-    input: String,
-
-    #[arg(long)]
-    debug: bool,
-}
+use advent::common::{read_lines, Line, Token};
+use advent::solution::{Answer, Solution};
+use advent::{debug, trace};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
 
 fn hash(input: &str) -> i64 {
     let mut total = 0i64;
@@ -59,22 +49,21 @@ impl LensBox {
         self.lenses.retain(|(s, _)| s != label);
     }
 
-    fn score(&self, debug: bool) -> i64 {
+    fn score(&self) -> i64 {
         let mut total = 0i64;
         for (i, (s, n)) in self.lenses.iter().enumerate() {
             let i = i as i64 + 1;
-            if debug {
-                println!("{}: {} * {} = {}", s, i, n, i * n);
-            }
+            debug!("{}: {} * {} = {}", s, i, n, i * n);
             total += i * n;
         }
         total
     }
 
-    fn print(&self) {
-        for (s, n) in self.lenses.iter() {
-            print!("[{} {}] ", s, n);
-        }
+    fn render(&self) -> String {
+        self.lenses
+            .iter()
+            .map(|(s, n)| format!("[{} {}]", s, n))
+            .join(" ")
     }
 }
 
@@ -85,14 +74,13 @@ struct Drawer {
 impl Drawer {
     fn new() -> Self {
         let mut v = Vec::new();
-        v.resize_with(256, || LensBox::new());
+        v.resize_with(256, LensBox::new);
         Drawer { boxes: v }
     }
 
-    fn apply(&mut self, cmd: &str, debug: bool) -> Result<()> {
-        if debug {
-            println!("applying command {}", cmd);
-        }
+    fn apply(&mut self, token: Token) -> Result<()> {
+        let cmd = token.text();
+        debug!("applying command {}", cmd);
         if let Some(i) = cmd.find('-') {
             let cmd = &cmd[..i];
             let b = hash(cmd) as usize;
@@ -102,7 +90,7 @@ impl Drawer {
                 .delete(cmd);
             Ok(())
         } else if let Some(eq) = cmd.find('=') {
-            let n = cmd[eq + 1..].parse::<i64>()?;
+            let n = token.slice(&cmd[eq + 1..]).parse::<i64>()?;
             let cmd = &cmd[..eq];
             let b = hash(cmd) as usize;
             self.boxes
@@ -115,70 +103,58 @@ impl Drawer {
         }
     }
 
-    fn score(&self, debug: bool) -> i64 {
+    fn score(&self) -> i64 {
         let mut total = 0i64;
         for (i, b) in self.boxes.iter().enumerate() {
-            if b.lenses.len() == 0 {
+            if b.lenses.is_empty() {
                 continue;
             }
             let i = i as i64 + 1;
-            let s = b.score(debug);
-            if debug {
-                println!("{} * {} = {}", i, s, i * s);
-            }
+            let s = b.score();
+            debug!("{} * {} = {}", i, s, i * s);
             total += i * s;
         }
         total
     }
 
-    fn print(&self) {
+    fn render(&self) -> String {
+        let mut s = String::new();
         for (i, b) in self.boxes.iter().enumerate() {
-            if b.lenses.len() == 0 {
-                continue;
+            if !b.lenses.is_empty() {
+                s.push_str(&format!("\nBox {}: {}", i, b.render()));
             }
-            print!("Box {}: ", i);
-            b.print();
-            println!("");
         }
+        s
     }
 }
 
-fn part2(input: &str, debug: bool) -> Result<i64> {
-    let input = input.trim();
+fn part2(input: &[Line]) -> Result<i64> {
     let mut drawer = Drawer::new();
-    for op in input.split(',') {
-        drawer.apply(op, debug)?;
-        if debug {
-            drawer.print();
-            println!("");
+    for line in input {
+        for op in line.split(',') {
+            drawer.apply(line.token(op))?;
+            trace!("{}", drawer.render());
         }
     }
-    Ok(drawer.score(debug))
+    Ok(drawer.score())
 }
 
-fn process_file(path: &str, debug: bool) -> Result<()> {
-    let mut file = File::open(path).with_context(|| format!("unable to open file {:?}", path))?;
-    let mut s = String::new();
-    file.read_to_string(&mut s)?;
+pub struct Day15;
 
-    let ans1 = hash(&s[..]);
-    println!("ans1: {}", ans1);
+impl Solution for Day15 {
+    type Input = Vec<Line>;
 
-    let ans2 = part2(&s[..], debug)?;
-    println!("ans2: {}", ans2);
-
-    Ok(())
-}
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        Ok(read_lines(path)?.collect())
+    }
 
-fn process(args: &Args) -> Result<()> {
-    process_file(&args.input, args.debug)?;
-    Ok(())
-}
+    // Newlines are ignored, so the lines are hashed as if they were one.
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let text: String = input.iter().map(|line| line.text.as_str()).collect();
+        Ok(hash(&text).into())
+    }
 
-fn main() {
-    let args = Args::parse();
-    match process(&args) {
-        Ok(_) => (),
-        Err(error) => panic!("{:?}", error),
-    };
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
 }
@@ -0,0 +1,206 @@
+use advent::common::{read_grid, Array2D, CommonError, Topology};
+use advent::solution::{Answer, Solution};
+use anyhow::{bail, Result};
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+enum Direction {
+    North = 1,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+struct Beam {
+    x: usize,
+    y: usize,
+    dir: Direction,
+}
+
+impl Direction {
+    // Returns the (row, column) delta for moving one step in this direction.
+    fn delta(&self) -> (i64, i64) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::South => (1, 0),
+            Direction::East => (0, 1),
+            Direction::West => (0, -1),
+        }
+    }
+}
+
+impl Beam {
+    fn new(x: usize, y: usize, dir: Direction) -> Self {
+        Beam { x, y, dir }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    map: Array2D<char>,
+    width: usize,
+    height: usize,
+}
+
+impl Input {
+    // Moves the beam one step in `dir`, or records it in `ends` if that would leave the grid.
+    fn advance(
+        &self,
+        beam: Beam,
+        dir: Direction,
+        q: &mut VecDeque<Beam>,
+        ends: &mut HashSet<Beam>,
+    ) {
+        let (dr, dc) = dir.delta();
+        match self.map.offset(beam.y, beam.x, dr, dc, Topology::Bounded) {
+            Some((y, x)) => q.push_back(Beam::new(x, y, dir)),
+            None => {
+                ends.insert(beam);
+            }
+        }
+    }
+
+    // Cast a beam starting at (x, y) and going in direction.
+    // `ends` returns the set of spots where the beam terminated.
+    // Returns the number of "activated" cells.
+    fn fill(&self, x: usize, y: usize, dir: Direction, ends: &mut HashSet<Beam>) -> usize {
+        let mut q: VecDeque<Beam> = VecDeque::new();
+        let mut seen: HashSet<Beam> = HashSet::new();
+        let mut on = Array2D::from_rows(vec![vec![false; self.width]; self.height])
+            .expect("rows are the same length");
+
+        let mut score = 0usize;
+        q.push_back(Beam { x, y, dir });
+
+        while let Some(beam) = q.pop_front() {
+            let c = self.map[(beam.y, beam.x)];
+
+            // If we hit anything other than '.', check whether we've been here before.
+            // Repeats are rare, and dots are common, so only check for repeats on other obstacles.
+            if c != '.' && !seen.insert(beam) {
+                continue;
+            }
+
+            let is_on = &mut on[(beam.y, beam.x)];
+            if !*is_on {
+                *is_on = true;
+                score += 1;
+            }
+
+            let dir = beam.dir;
+
+            match dir {
+                Direction::North => {
+                    if c == '.' || c == '|' {
+                        self.advance(beam, dir, &mut q, ends);
+                    }
+                    if c == '/' || c == '-' {
+                        self.advance(beam, Direction::East, &mut q, ends);
+                    }
+                    if c == '\\' || c == '-' {
+                        self.advance(beam, Direction::West, &mut q, ends);
+                    }
+                }
+                Direction::South => {
+                    if c == '.' || c == '|' {
+                        self.advance(beam, dir, &mut q, ends);
+                    }
+                    if c == '/' || c == '-' {
+                        self.advance(beam, Direction::West, &mut q, ends);
+                    }
+                    if c == '\\' || c == '-' {
+                        self.advance(beam, Direction::East, &mut q, ends);
+                    }
+                }
+                Direction::West => {
+                    if c == '.' || c == '-' {
+                        self.advance(beam, dir, &mut q, ends);
+                    }
+                    if c == '/' || c == '|' {
+                        self.advance(beam, Direction::South, &mut q, ends);
+                    }
+                    if c == '\\' || c == '|' {
+                        self.advance(beam, Direction::North, &mut q, ends);
+                    }
+                }
+                Direction::East => {
+                    if c == '.' || c == '-' {
+                        self.advance(beam, dir, &mut q, ends);
+                    }
+                    if c == '/' || c == '|' {
+                        self.advance(beam, Direction::North, &mut q, ends);
+                    }
+                    if c == '\\' || c == '|' {
+                        self.advance(beam, Direction::South, &mut q, ends);
+                    }
+                }
+            }
+        }
+        score
+    }
+}
+
+fn read_input(path: &str) -> Result<Input> {
+    let map = read_grid(path, |c| -> Result<char, CommonError> { Ok(c) })?;
+    let height = map.rows();
+    let width = map.columns();
+    if width == 0 {
+        bail!("map is empty");
+    }
+    Ok(Input { map, width, height })
+}
+
+fn most_energized(input: &Input) -> usize {
+    let mut ends = HashSet::new();
+    let mut best = 0;
+    for i in 0..input.width {
+        if !ends.contains(&Beam::new(i, 0, Direction::North)) {
+            best = best.max(input.fill(i, 0, Direction::South, &mut ends));
+        }
+        if !ends.contains(&Beam::new(i, input.height - 1, Direction::South)) {
+            best = best.max(input.fill(i, input.height - 1, Direction::North, &mut ends));
+        }
+    }
+    for i in 0..input.height {
+        if !ends.contains(&Beam::new(0, i, Direction::West)) {
+            best = best.max(input.fill(0, i, Direction::East, &mut ends));
+        }
+        if !ends.contains(&Beam::new(input.width - 1, i, Direction::East)) {
+            best = best.max(input.fill(input.width - 1, i, Direction::West, &mut ends));
+        }
+    }
+    best
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Input;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let mut ends = HashSet::new();
+        Ok(input.fill(0, 0, Direction::East, &mut ends).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(most_energized(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day16.parse("data/16/sample.txt").unwrap();
+        assert_eq!(Day16.part1(&input).unwrap(), 46.into());
+        assert_eq!(Day16.part2(&input).unwrap(), 51.into());
+    }
+}
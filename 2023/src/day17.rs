@@ -0,0 +1,189 @@
+use advent::common::{read_grid, resumable_dijkstra, Array2D, Graph, Topology};
+use advent::solution::{record_counter, Answer, Solution};
+use advent::{debug, trace};
+use anyhow::{Context, Error, Result};
+use serde::{Deserialize, Serialize};
+
+pub struct Input {
+    grid: Array2D<i32>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+enum Direction {
+    North = 1,
+    South,
+    East,
+    West,
+}
+
+// Node is a location and how far you've traveled so far in the current direction.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+struct Node {
+    row: usize,
+    col: usize,
+    dir: Direction,
+    count: u8,
+}
+
+impl Direction {
+    fn delta(&self) -> (i64, i64) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+            Direction::East => (0, 1),
+        }
+    }
+
+    fn reverse(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+            Direction::East => Direction::West,
+        }
+    }
+}
+
+impl Input {
+    fn read(path: &str) -> Result<Self> {
+        let grid =
+            read_grid::<i32, Error>(
+                path,
+                |c| Ok(c.to_digit(10).context("invalid digit")? as i32),
+            )?;
+        Ok(Input { grid })
+    }
+
+    fn can_move(&self, node: &Node, dir: Direction, min: u8, max: u8) -> bool {
+        if dir != node.dir && node.count < min {
+            return false;
+        }
+        if dir == node.dir && node.count >= max {
+            return false;
+        }
+        dir != node.dir.reverse()
+    }
+
+    fn try_move(&self, node: &Node, dir: Direction, min: u8, max: u8) -> Option<Node> {
+        if !self.can_move(node, dir, min, max) {
+            return None;
+        }
+        let (dr, dc) = dir.delta();
+        let (row, col) = self
+            .grid
+            .offset(node.row, node.col, dr, dc, Topology::Bounded)?;
+        let count = if dir == node.dir { node.count + 1 } else { 1 };
+        Some(Node {
+            row,
+            col,
+            dir,
+            count,
+        })
+    }
+
+    // Returns the set of nodes you can get to from this one.
+    fn next(&self, node: &Node, min: u8, max: u8) -> Vec<Node> {
+        let mut v = Vec::new();
+        if let Some(next) = self.try_move(node, Direction::North, min, max) {
+            v.push(next);
+        }
+        if let Some(next) = self.try_move(node, Direction::South, min, max) {
+            v.push(next);
+        }
+        if let Some(next) = self.try_move(node, Direction::East, min, max) {
+            v.push(next);
+        }
+        if let Some(next) = self.try_move(node, Direction::West, min, max) {
+            v.push(next);
+        }
+        v
+    }
+}
+
+// The graph of Nodes, for a crucible that must go at least `min` and at most `max` blocks
+// in a straight line.
+struct Crucible<'a> {
+    input: &'a Input,
+    min: u8,
+    max: u8,
+}
+
+impl<'a> Graph for Crucible<'a> {
+    type Node = Node;
+
+    fn successors(&self, node: &Node) -> Vec<(Node, i64)> {
+        self.input
+            .next(node, self.min, self.max)
+            .into_iter()
+            .map(|n| (n, self.input.grid[(n.row, n.col)] as i64))
+            .collect()
+    }
+
+    fn is_goal(&self, node: &Node) -> bool {
+        node.count >= self.min
+            && node.row == self.input.grid.rows() - 1
+            && node.col == self.input.grid.columns() - 1
+    }
+}
+
+impl Input {
+    fn search(&self, part2: bool) -> Result<i64> {
+        let (min, max) = if part2 { (4, 10) } else { (0, 3) };
+        let crucible = Crucible {
+            input: self,
+            min,
+            max,
+        };
+
+        let starts = [Direction::South, Direction::East].map(|dir| Node {
+            row: 0,
+            col: 0,
+            dir,
+            count: 0,
+        });
+        let key = format!("crucible {}..={}", min, max);
+        let result = resumable_dijkstra(&crucible, starts, &key)?.context("no result found")?;
+        record_counter("nodes_expanded", result.stats.expanded as u64);
+        record_counter("nodes_visited", result.stats.visited as u64);
+        for node in result.path.iter() {
+            trace!("{:?}", node);
+        }
+        debug!("{:?}", result.stats);
+        Ok(result.cost)
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Input;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        Input::read(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.search(false)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.search(true)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let input = Day17.parse("data/17/sample.txt").unwrap();
+        assert_eq!(Day17.part1(&input).unwrap(), 102.into());
+        assert_eq!(Day17.part2(&input).unwrap(), 94.into());
+
+        // Ultra crucibles can't stop after fewer than four blocks, even at the end.
+        let input = Day17.parse("data/17/sample2.txt").unwrap();
+        assert_eq!(Day17.part2(&input).unwrap(), 71.into());
+    }
+}
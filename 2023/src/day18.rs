@@ -1,11 +1,17 @@
-use advent::common::{read_lines, split_on, Array2D};
-use anyhow::{anyhow, bail, Context, Result};
-use clap::Parser;
+#[cfg(feature = "sdl")]
+use advent::common::log::Level;
+use advent::common::render::{self, Image};
+use advent::common::{read_lines, Array2D, Line, Template, Topology};
+#[cfg(feature = "sdl")]
+use advent::log_enabled;
+use advent::solution::{Answer, Part, Solution, Strategy};
+#[cfg(feature = "sdl")]
+use advent::viewer::{GridView, Viewer};
+use advent::{debug, trace};
+use anyhow::{anyhow, bail, Result};
 use itertools::Itertools;
-use sdl2::{event::Event, keyboard::Keycode, pixels::Color, rect::Rect};
 use std::collections::{HashMap, VecDeque};
 use std::str::FromStr;
-use std::time::Duration;
 
 #[derive(Debug)]
 enum Direction {
@@ -33,17 +39,18 @@ impl FromStr for Direction {
 }
 
 impl Direction {
-    fn from_digit(c: char) -> Result<Self> {
-        match c {
-            '0' => Ok(Direction::Right),
-            '1' => Ok(Direction::Down),
-            '2' => Ok(Direction::Left),
-            '3' => Ok(Direction::Up),
-            _ => Err(anyhow!("invalid direction: {}", c)),
+    fn from_digit(s: &str) -> Result<Self> {
+        match s {
+            "0" => Ok(Direction::Right),
+            "1" => Ok(Direction::Down),
+            "2" => Ok(Direction::Left),
+            "3" => Ok(Direction::Up),
+            _ => Err(anyhow!("invalid direction: {}", s)),
         }
     }
 }
 
+// The color is only used when drawing the grid.
 #[derive(Debug, Clone, Copy)]
 struct CellColor {
     r: u8,
@@ -65,9 +72,9 @@ impl FromStr for CellColor {
     }
 }
 
-impl Into<Color> for CellColor {
-    fn into(self) -> Color {
-        Color::RGB(self.r, self.g, self.b)
+impl From<CellColor> for render::Color {
+    fn from(c: CellColor) -> Self {
+        render::Color::rgb(c.r, c.g, c.b)
     }
 }
 
@@ -79,46 +86,43 @@ struct Record {
 }
 
 impl Record {
-    fn from_str(s: &str, part2: bool) -> Result<Self> {
-        let (dir1, rest) = split_on(s, ' ').context("missing first space")?;
-        let (amount1, color) = split_on(rest, ' ').context("missing second space")?;
-        if color.len() != 9 {
-            bail!("invalid color part: {}", color)
+    // Reads a line such as "R 6 (#70c710)". Part 2 takes the amount and direction from
+    // the color's hex digits instead.
+    fn read(template: &Template, line: &Line, part2: bool) -> Result<Self> {
+        let c = template.captures(line)?;
+        let color: CellColor = c.get("color")?;
+        if !part2 {
+            return Ok(Record {
+                dir: c.get("dir")?,
+                amount: c.get("amount")?,
+                color,
+            });
         }
-        let color = &color[1..8];
-
-        let dir1 = dir1.parse()?;
-        let dir2 = Direction::from_digit(color[6..].chars().next().expect("checked bounds"))?;
-
-        let amount1 = amount1.parse()?;
-        let amount2 = i64::from_str_radix(&color[1..6], 16)
-            .with_context(|| format!("invalid hex: {}", &color[1..6]))?;
-
-        let color = color.parse()?;
-
-        let dir = if part2 { dir2 } else { dir1 };
-        let amount = if part2 { amount2 } else { amount1 };
-
+        let hex = c.str("color");
+        let amount = line
+            .token(&hex[1..6])
+            .parse_with(|s| i64::from_str_radix(s, 16))?;
+        let dir = line.token(&hex[6..]).parse_with(Direction::from_digit)?;
         Ok(Record { dir, amount, color })
     }
 }
 
-fn read_input(path: &str, part2: bool) -> Result<Vec<Record>> {
-    let mut v = Vec::new();
-    for line in read_lines(path)? {
-        v.push(Record::from_str(line.as_str(), part2)?);
-    }
-    Ok(v)
-}
-
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 enum Cell {
     Empty,
     Trench(CellColor),
     Filled,
 }
 
-fn create_grid(input: &Vec<Record>, debug: bool) -> Result<Array2D<Cell>> {
+fn cell_color(cell: &Cell) -> render::Color {
+    match cell {
+        Cell::Empty => render::Color::BLACK,
+        Cell::Filled => render::Color::rgb(20, 20, 20),
+        Cell::Trench(color) => (*color).into(),
+    }
+}
+
+fn create_grid(input: &[Record]) -> Result<Array2D<Cell>> {
     let mut row = 0i64;
     let mut col = 0i64;
     let mut min_row = 0i64;
@@ -137,12 +141,10 @@ fn create_grid(input: &Vec<Record>, debug: bool) -> Result<Array2D<Cell>> {
         min_col = min_col.min(col);
         max_col = max_col.max(col);
     }
-    if debug {
-        println!(
-            "Position ranges from ({}, {}) to ({}, {})",
-            min_row, min_col, max_row, max_col
-        );
-    }
+    debug!(
+        "Position ranges from ({}, {}) to ({}, {})",
+        min_row, min_col, max_row, max_col
+    );
 
     let rows = ((max_row - min_row) + 1) as usize;
     let cols = ((max_col - min_col) + 1) as usize;
@@ -168,13 +170,13 @@ fn create_grid(input: &Vec<Record>, debug: bool) -> Result<Array2D<Cell>> {
             row += rstep;
             let i = (row - min_row) as usize;
             let j = (col - min_col) as usize;
-            grid[(i, j)] = Cell::Trench(rec.color.clone());
+            grid[(i, j)] = Cell::Trench(rec.color);
         }
         for _ in 0..dc {
             col += cstep;
             let i = (row - min_row) as usize;
             let j = (col - min_col) as usize;
-            grid[(i, j)] = Cell::Trench(rec.color.clone());
+            grid[(i, j)] = Cell::Trench(rec.color);
         }
     }
 
@@ -193,35 +195,19 @@ fn fill(grid: &mut Array2D<Cell>) {
     }
     while let Some((r, c)) = q.pop_front() {
         if let Cell::Empty = grid[(r, c)] {
-            if r > 0 {
-                q.push_back((r - 1, c));
-            }
-            if c > 0 {
-                q.push_back((r, c - 1));
-            }
-            if r < grid.rows() - 1 {
-                q.push_back((r + 1, c));
-            }
-            if c < grid.columns() - 1 {
-                q.push_back((r, c + 1));
-            }
+            q.extend(
+                grid.neighbors4(r, c, Topology::Bounded)
+                    .map(|(r, c, _)| (r, c)),
+            );
             grid[(r, c)] = Cell::Filled;
         }
     }
 }
 
-fn count_unfilled(grid: &Array2D<Cell>) -> u64 {
-    let mut total = 0;
-    for r in 0..grid.rows() {
-        for c in 0..grid.columns() {
-            match &grid[(r, c)] {
-                Cell::Empty => total += 1,
-                Cell::Trench(_) => total += 1,
-                Cell::Filled => {}
-            }
-        }
-    }
-    total
+fn count_unfilled(grid: &Array2D<Cell>) -> usize {
+    grid.enumerate()
+        .filter(|(_, _, cell)| !matches!(cell, Cell::Filled))
+        .count()
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -238,7 +224,7 @@ struct HorizontalSegment {
     max_col: i64,
 }
 
-fn create_segments(records: &Vec<Record>) -> (Vec<HorizontalSegment>, Vec<VerticalSegment>) {
+fn create_segments(records: &[Record]) -> (Vec<HorizontalSegment>, Vec<VerticalSegment>) {
     let mut h = Vec::new();
     let mut v = Vec::new();
     let mut r = 0i64;
@@ -286,13 +272,10 @@ fn create_segments(records: &Vec<Record>) -> (Vec<HorizontalSegment>, Vec<Vertic
 
 fn compute_area_for_row(
     row: i64,
-    horizontal: &Vec<HorizontalSegment>,
-    vertical: &Vec<VerticalSegment>,
-    debug: bool,
+    horizontal: &[HorizontalSegment],
+    vertical: &[VerticalSegment],
 ) -> Result<i64> {
-    if debug {
-        println!("computing area for row {}", row);
-    }
+    trace!("computing area for row {}", row);
 
     // Find all the horizontal segments on this row.
     let horizontal = horizontal.iter().filter(|seg| seg.row == row).collect_vec();
@@ -306,7 +289,7 @@ fn compute_area_for_row(
     // Sort all the segments by either min_col or col.
     let mut h_map = HashMap::new();
     for h in horizontal {
-        h_map.insert(h.min_col, h.clone());
+        h_map.insert(h.min_col, *h);
     }
     vertical.sort_by_key(|seg| seg.column);
 
@@ -316,9 +299,7 @@ fn compute_area_for_row(
     let mut trailing_edge: Option<HorizontalSegment> = None;
     let mut previous_column = i64::MIN;
     for v_seg in vertical {
-        if debug {
-            println!("considering vertical segment {:?}", v_seg);
-        }
+        trace!("considering vertical segment {:?}", v_seg);
         let column = v_seg.column;
 
         if inside {
@@ -334,9 +315,7 @@ fn compute_area_for_row(
 
         if let Some(h_seg) = trailing_edge {
             // This better be the end of an edge.
-            if debug {
-                println!("this is a trailing edge");
-            }
+            trace!("this is a trailing edge");
             if column != h_seg.max_col {
                 bail!("edge does not end at corner.");
             }
@@ -360,14 +339,14 @@ fn compute_area_for_row(
             let old_seg_len = if inside { column - previous_column } else { 1 };
             let new_seg_len = h_seg.max_col - h_seg.min_col;
             let seg_len = old_seg_len + new_seg_len;
-            if debug {
-                println!(
-                    "this is a leading edge. adding length {} + {} = {}",
-                    old_seg_len, new_seg_len, seg_len
-                );
-            }
+            trace!(
+                "this is a leading edge. adding length {} + {} = {}",
+                old_seg_len,
+                new_seg_len,
+                seg_len
+            );
             total += seg_len;
-            trailing_edge = Some(h_seg.clone());
+            trailing_edge = Some(*h_seg);
             if v_seg.min_row == row {
                 top_edge = true;
             } else if v_seg.max_row == row {
@@ -378,9 +357,7 @@ fn compute_area_for_row(
         } else {
             // This is not any kind of corner.
             let seg_len = if inside { column - previous_column } else { 1 };
-            if debug {
-                println!("this is not a corner. adding length {}", seg_len);
-            }
+            trace!("this is not a corner. adding length {}", seg_len);
             total += seg_len;
             inside = !inside;
         }
@@ -392,13 +369,11 @@ fn compute_area_for_row(
         bail!("still inside at end of row {}", row);
     }
 
-    if debug {
-        println!("returning area {} for row {}", total, row);
-    }
+    trace!("returning area {} for row {}", total, row);
     Ok(total)
 }
 
-fn compute_area_by_segments(records: &Vec<Record>, debug: bool) -> Result<i64> {
+fn compute_area_by_segments(records: &[Record]) -> Result<i64> {
     let mut total = 0;
 
     // Get all segments.
@@ -409,9 +384,7 @@ fn compute_area_by_segments(records: &Vec<Record>, debug: bool) -> Result<i64> {
 
     let mut previous_row = None;
     for segment in horizontal.iter() {
-        if debug {
-            println!("considering horizontal segment {:?}", segment);
-        }
+        debug!("considering horizontal segment {:?}", segment);
         let row = segment.row;
 
         if let Some(prev) = previous_row {
@@ -420,129 +393,102 @@ fn compute_area_by_segments(records: &Vec<Record>, debug: bool) -> Result<i64> {
             }
             if prev != row - 1 {
                 // Compute the area for the row above this one.
-                let row_area = compute_area_for_row(row - 1, &horizontal, &vertical, debug)?;
+                let row_area = compute_area_for_row(row - 1, &horizontal, &vertical)?;
 
                 // Multiply it by the height.
                 let height = row - prev - 1;
                 let area = row_area * height;
-                if debug {
-                    println!(
-                        "add area for previous section with row area {} * height {} = {}",
-                        row_area, height, area
-                    );
-                }
+                debug!(
+                    "add area for previous section with row area {} * height {} = {}",
+                    row_area, height, area
+                );
                 total += area;
             }
         }
 
         previous_row = Some(row);
-        let row_area = compute_area_for_row(row, &horizontal, &vertical, debug)?;
+        let row_area = compute_area_for_row(row, &horizontal, &vertical)?;
         total += row_area;
     }
 
     Ok(total)
 }
 
-#[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Args {
-    #[arg(short, long)]
-    input: String,
+pub struct Input {
+    part1: Vec<Record>,
+    part2: Vec<Record>,
+}
 
-    #[arg(long)]
-    debug: bool,
+fn read_input(path: &str) -> Result<Input> {
+    let template = Template::new("{dir} {amount} ({color})");
+    let mut part1 = Vec::new();
+    let mut part2 = Vec::new();
+    for line in read_lines(path)? {
+        part1.push(Record::read(&template, &line, false)?);
+        part2.push(Record::read(&template, &line, true)?);
+    }
+    Ok(Input { part1, part2 })
 }
 
-fn display_grid(grid: &Array2D<Cell>) -> Result<()> {
-    let sdl_context = sdl2::init().unwrap();
-    let video_subsystem = sdl_context.video().unwrap();
-
-    let window = video_subsystem
-        .window("AoC 2023 - Day 18", 1920, 1080)
-        .position_centered()
-        .resizable()
-        .build()
-        .unwrap();
-
-    let mut canvas = window.into_canvas().build().unwrap();
-
-    canvas.set_logical_size(grid.columns() as u32, grid.rows() as u32)?;
-    canvas.set_draw_color(Color::RGB(40, 40, 40));
-    canvas.clear();
-    canvas.present();
-
-    let mut event_pump = sdl_context.event_pump().unwrap();
-    'running: loop {
-        canvas.set_draw_color(Color::RGB(40, 40, 40));
-        canvas.clear();
-
-        for event in event_pump.poll_iter() {
-            match event {
-                Event::Quit { .. }
-                | Event::KeyDown {
-                    keycode: Some(Keycode::Escape),
-                    ..
-                } => break 'running,
-                _ => {}
-            }
-        }
+pub struct Day18;
 
-        for r in 0..grid.rows() {
-            for c in 0..grid.columns() {
-                let cell = &grid[(r, c)];
-                let color = match cell {
-                    Cell::Empty => Color::BLACK,
-                    Cell::Filled => Color::RGB(20, 20, 20),
-                    Cell::Trench(col) => col.clone().into(),
-                };
-                canvas.set_draw_color(color);
-                canvas
-                    .draw_rect(Rect::new(c as i32, r as i32, 1, 1))
-                    .map_err(|s| anyhow!("{}", s))?;
-            }
-        }
+impl Solution for Day18 {
+    type Input = Input;
 
-        canvas.present();
-        ::std::thread::sleep(Duration::new(0, 1_000_000_000u32 / 60));
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
     }
 
-    Ok(())
-}
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let mut grid = create_grid(&input.part1)?;
+        fill(&mut grid);
+        let ans = count_unfilled(&grid);
 
-fn part1(args: &Args) -> Result<()> {
-    let input = read_input(args.input.as_str(), false)?;
-    let mut grid = create_grid(&input, args.debug)?;
-    fill(&mut grid);
-    let ans = count_unfilled(&grid);
-    println!("ans 1 (method 1): {}", ans);
+        if let Some(path) = render::output("2023-18-lagoon.png") {
+            Image::render(&grid, 1, cell_color).write_png(&path)?;
+            debug!("wrote {}", path.display());
+        }
 
-    let ans = compute_area_by_segments(&input, args.debug)?;
-    println!("ans 1 (method 2): {}", ans);
+        #[cfg(feature = "sdl")]
+        if log_enabled!(Level::Debug) {
+            Viewer::new("AoC 2023 - Day 18").show(&GridView::new(&grid, cell_color))?;
+        }
 
-    if args.debug {
-        display_grid(&grid)?;
+        Ok(ans.into())
     }
 
-    Ok(())
-}
-
-fn part2(args: &Args) -> Result<()> {
-    let input = read_input(args.input.as_str(), true)?;
-    let ans = compute_area_by_segments(&input, args.debug)?;
-    println!("ans 2 (method 2): {}", ans);
-    Ok(())
-}
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(compute_area_by_segments(&input.part2)?.into())
+    }
 
-fn process(args: &Args) -> Result<()> {
-    part1(args)?;
-    part2(args)?;
-    Ok(())
+    // The grid is too big to fill for part 2, but segments work for both.
+    fn strategies(&self, part: Part) -> Vec<Strategy<Self::Input>> {
+        match part {
+            Part::One => vec![Strategy {
+                name: "segments",
+                solve: |input| Ok(compute_area_by_segments(&input.part1)?.into()),
+            }],
+            Part::Two => Vec::new(),
+        }
+    }
 }
 
-fn main() {
-    let args = Args::parse();
-    match process(&args) {
-        Ok(_) => (),
-        Err(error) => panic!("{:?}", error),
-    };
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day18.parse("data/18/sample.txt").unwrap();
+        assert_eq!(Day18.part1(&input).unwrap(), 62.into());
+        assert_eq!(Day18.part2(&input).unwrap(), 952408144115_i64.into());
+        for strategy in Day18.strategies(Part::One) {
+            assert_eq!(
+                (strategy.solve)(&input).unwrap(),
+                62.into(),
+                "{}",
+                strategy.name
+            );
+        }
+    }
 }
@@ -1,11 +1,11 @@
-use advent::common::split_on;
+use advent::common::log::Level;
+use advent::common::{read_sections, split_on, Interval, Line, Region, Template, Token};
+use advent::solution::{Answer, Solution};
+use advent::{debug, log_enabled, trace};
 use anyhow::{anyhow, bail, Context, Result};
-use clap::Parser;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::{self, Display};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::ops::{Index, IndexMut, RangeInclusive};
+use std::ops::{Index, IndexMut};
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy)]
@@ -48,7 +48,7 @@ impl Display for Field {
     }
 }
 
-struct Part {
+pub struct Part {
     x: i64,
     m: i64,
     a: i64,
@@ -85,44 +85,16 @@ impl IndexMut<Field> for Part {
     }
 }
 
-impl FromStr for Part {
-    type Err = anyhow::Error;
-
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        if !input.starts_with("{x=") {
-            bail!("missing x: {}", input);
-        }
-        let rest = &input[3..];
-        let (x, rest) = split_on(rest, ',').context(format!("missing first comma: {}", input))?;
-
-        if !rest.starts_with("m=") {
-            bail!("missing m: {}", input);
-        }
-        let rest = &rest[2..];
-        let (m, rest) = split_on(rest, ',').context(format!("missing second comma: {}", input))?;
-
-        if !rest.starts_with("a=") {
-            bail!("missing a: {}", input);
-        }
-        let rest = &rest[2..];
-        let (a, rest) = split_on(rest, ',').context(format!("missing third comma: {}", input))?;
-
-        if !rest.starts_with("s=") {
-            bail!("missing s: {}", input);
-        }
-        let rest = &rest[2..];
-        let (s, rest) = split_on(rest, '}').context(format!("missing closing brace: {}", input))?;
-
-        if rest.len() != 0 {
-            bail!("trailing chars: {}", input);
-        }
-
-        let x = x.parse()?;
-        let m = m.parse()?;
-        let a = a.parse()?;
-        let s = s.parse()?;
-
-        Ok(Part { x, m, a, s })
+impl Part {
+    fn read(template: &Template, line: &Line) -> Result<Self> {
+        let token = line.token(line.trim());
+        let c = template.captures(&token)?;
+        Ok(Part {
+            x: c.get("x")?,
+            m: c.get("m")?,
+            a: c.get("a")?,
+            s: c.get("s")?,
+        })
     }
 }
 
@@ -137,7 +109,7 @@ impl FromStr for Consequent {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() == 0 {
+        if s.is_empty() {
             bail!("invalid consequent: {}", s);
         }
         if s.chars().any(|c| !c.is_alphabetic()) {
@@ -203,12 +175,11 @@ struct Rule {
     consequent: Consequent,
 }
 
-impl FromStr for Rule {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl Rule {
+    fn read(token: Token) -> Result<Self> {
+        let s = token.text();
         let (antecedent, consequent) = split_on(s, ':').context(format!("invalid rule: {}", s))?;
-        let consequent = consequent.parse()?;
+        let consequent = token.slice(consequent).parse()?;
 
         let (op, condition_op) = if antecedent.contains('<') {
             ('<', Op::LessThan)
@@ -217,8 +188,8 @@ impl FromStr for Rule {
         };
 
         let (field, amount) = split_on(antecedent, op).context(format!("invalid rule: {}", s))?;
-        let condition_field = field.parse()?;
-        let condition_amount = amount.parse()?;
+        let condition_field = token.slice(field).parse()?;
+        let condition_amount = token.slice(amount).parse()?;
 
         Ok(Rule {
             condition_field,
@@ -268,10 +239,9 @@ impl Display for Workflow {
     }
 }
 
-impl FromStr for Workflow {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl Workflow {
+    fn read(token: Token) -> Result<Self> {
+        let s = token.text();
         let (name, rules) = split_on(s, '{').context(format!("invalid workflow: {}", s))?;
         let name = name.to_owned();
         if !rules.ends_with('}') {
@@ -282,10 +252,10 @@ impl FromStr for Workflow {
         let mut rules_str = rules;
         let mut rules = Vec::new();
         while let Some((rule, rest)) = split_on(rules_str, ',') {
-            rules.push(rule.parse()?);
+            rules.push(Rule::read(token.slice(rule))?);
             rules_str = rest;
         }
-        let fallback = rules_str.parse()?;
+        let fallback = token.slice(rules_str).parse()?;
 
         Ok(Workflow {
             name,
@@ -306,90 +276,41 @@ impl Workflow {
     }
 }
 
-type Range = RangeInclusive<usize>;
-
-// Given a range and a condition, returns the subrange that matches the condition and the subrange that doesn't.
-fn split_range(range: &Range, rule: &Rule) -> (Option<Range>, Option<Range>) {
-    let n = rule.condition_amount as usize;
-    let start = *range.start();
-    let end = *range.end();
-    match rule.condition_op {
-        Op::LessThan => {
-            if n <= *range.start() {
-                (None, Some(start..=end))
-            } else if n > *range.end() {
-                (Some(start..=end), None)
-            } else {
-                (Some(start..=n - 1), Some(n..=end))
-            }
-        }
-        Op::GreaterThan => {
-            if n >= *range.end() {
-                (None, Some(start..=end))
-            } else if n < *range.start() {
-                (Some(start..=end), None)
-            } else {
-                (Some(n + 1..=end), Some(start..=n))
-            }
-        }
-    }
-}
-
-fn range_len(range: &Range) -> usize {
-    (range.end() + 1) - range.start()
-}
-
+// The set of parts that could be accepted, with one axis per Field.
 #[derive(Clone, Debug)]
 struct Constraints {
-    x: Range,
-    m: Range,
-    a: Range,
-    s: Range,
+    region: Region<i64, 4>,
 }
 
 impl Constraints {
     fn new() -> Self {
         Constraints {
-            x: 1..=4000,
-            m: 1..=4000,
-            a: 1..=4000,
-            s: 1..=4000,
+            region: Region::new([Interval::inclusive(1, 4000); 4]),
         }
     }
 
-    // Creates a new Constraints with the constraints for field replaced with range.
-    fn with(&self, field: &Field, range: Range) -> Constraints {
-        let mut copy: Constraints = self.clone();
-        match field {
-            Field::X => copy.x = range,
-            Field::M => copy.m = range,
-            Field::A => copy.a = range,
-            Field::S => copy.s = range,
-        }
-        copy
-    }
-
     // Split the constraints into the set the matches the rule and the set that doesn't.
     fn split(&self, rule: &Rule) -> (Option<Constraints>, Option<Constraints>) {
-        let start_range = match rule.condition_field {
-            Field::X => &self.x,
-            Field::M => &self.m,
-            Field::A => &self.a,
-            Field::S => &self.s,
+        let axis = rule.condition_field as usize;
+        let n = rule.condition_amount;
+        let (matches, rest) = match rule.condition_op {
+            Op::LessThan => self.region.split_at(axis, n),
+            Op::GreaterThan => {
+                let (below, above) = self.region.split_at(axis, n + 1);
+                (above, below)
+            }
         };
-        let (r1, r2) = split_range(start_range, rule);
-        let c1 = r1.map(|r| self.with(&rule.condition_field, r));
-        let c2 = r2.map(|r| self.with(&rule.condition_field, r));
-        (c1, c2)
+        let wrap = |region| Constraints { region };
+        (matches.map(wrap), rest.map(wrap))
     }
 
     fn count(&self) -> usize {
-        range_len(&self.x) * range_len(&self.m) * range_len(&self.a) * range_len(&self.s)
+        self.region.volume() as usize
     }
 }
 
 #[derive(Debug)]
-struct Machine {
+pub struct Machine {
     workflows: HashMap<String, Workflow>,
 }
 
@@ -415,7 +336,7 @@ impl Machine {
         }
     }
 
-    fn apply_all(&self, parts: &Vec<Part>) -> Result<i64> {
+    fn apply_all(&self, parts: &[Part]) -> Result<i64> {
         let mut total = 0;
         for part in parts.iter() {
             if self.apply(part)? {
@@ -425,76 +346,60 @@ impl Machine {
         Ok(total)
     }
 
-    fn count_possibilities_internal(
-        &self,
-        state: &str,
-        constraints: Constraints,
-        debug: bool,
-    ) -> usize {
-        if debug {
-            println!("counting possibilities for {} in {:?}", state, constraints);
-        }
+    fn count_possibilities_internal(&self, state: &str, constraints: Constraints) -> usize {
+        trace!("counting possibilities for {} in {:?}", state, constraints);
         let mut total = 0;
         let mut current_constraints = constraints;
         let workflow = self.workflows.get(state).expect("missing constraint");
         for rule in workflow.rules.iter() {
-            if debug {
-                println!(
-                    "applying {} rule {} to {:?}",
-                    state, rule, current_constraints
-                );
-            }
+            trace!(
+                "applying {} rule {} to {:?}",
+                state,
+                rule,
+                current_constraints
+            );
             let (c1, c2) = current_constraints.split(rule);
             if let Some(c) = c1 {
-                if debug {
-                    println!("rule {} yields constraints {:?}", rule, c);
-                }
+                trace!("rule {} yields constraints {:?}", rule, c);
                 match &rule.consequent {
                     Consequent::Accept => total += c.count(),
                     Consequent::Reject => {}
-                    Consequent::Move(dest) => {
-                        total += self.count_possibilities_internal(dest, c, debug)
-                    }
+                    Consequent::Move(dest) => total += self.count_possibilities_internal(dest, c),
                 }
             } else {
-                if debug {
-                    println!("rule {} didn't match anything", rule);
-                }
+                trace!("rule {} didn't match anything", rule);
             }
             match c2 {
                 Some(c) => current_constraints = c,
                 None => {
-                    if debug {
-                        println!(
-                            "returning {} for {}, because the constraint set is empty",
-                            total, state
-                        );
-                    }
+                    trace!(
+                        "returning {} for {}, because the constraint set is empty",
+                        total,
+                        state
+                    );
                     return total;
                 }
             }
         }
-        if debug {
-            println!(
-                "applying {} fallback {} to {:?}",
-                state, &workflow.fallback, current_constraints
-            );
-        }
+        trace!(
+            "applying {} fallback {} to {:?}",
+            state,
+            &workflow.fallback,
+            current_constraints
+        );
         match &workflow.fallback {
             Consequent::Accept => total += current_constraints.count(),
             Consequent::Reject => {}
             Consequent::Move(dest) => {
-                total += self.count_possibilities_internal(dest, current_constraints, debug)
+                total += self.count_possibilities_internal(dest, current_constraints)
             }
         }
-        if debug {
-            println!("returning {} for {}", total, state);
-        }
+        trace!("returning {} for {}", total, state);
         total
     }
 
-    fn count_possibilities(&self, debug: bool) -> usize {
-        self.count_possibilities_internal("in", Constraints::new(), debug)
+    fn count_possibilities(&self) -> usize {
+        self.count_possibilities_internal("in", Constraints::new())
     }
 
     // Does a topological sort of all workflows, based on dependencies.
@@ -535,69 +440,46 @@ impl Machine {
 }
 
 fn read_input(path: &str) -> Result<(Machine, Vec<Part>)> {
+    let mut sections = read_sections(path)?.into_iter();
     let mut workflows = HashMap::new();
     let mut parts = Vec::new();
 
-    let f = File::open(path)?;
-    let mut f = BufReader::new(f);
-    let mut reading_parts = false;
-    loop {
-        let mut line = String::new();
-        let n = f.read_line(&mut line).unwrap();
-        let line = line.trim();
-
-        if line == "" {
-            if n == 0 {
-                break;
-            }
-            reading_parts = true;
-            continue;
-        }
-
-        if reading_parts {
-            parts.push(line.parse()?);
-        } else {
-            let workflow: Workflow = line.parse()?;
-            workflows.insert(workflow.name.clone(), workflow);
-        }
+    for line in sections.next().unwrap_or_default() {
+        let workflow = Workflow::read(line.token(line.trim()))?;
+        workflows.insert(workflow.name.clone(), workflow);
+    }
+    let template = Template::new("{x={x},m={m},a={a},s={s}}");
+    for line in sections.next().unwrap_or_default() {
+        parts.push(Part::read(&template, &line)?);
+    }
+    if let Some(extra) = sections.next() {
+        bail!("unexpected section at line {}", extra[0].number);
     }
 
     let machine = Machine { workflows };
     Ok((machine, parts))
 }
 
-#[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Args {
-    #[arg(short, long)]
-    input: String,
+pub struct Day19;
 
-    #[arg(long)]
-    debug: bool,
-}
+impl Solution for Day19 {
+    type Input = (Machine, Vec<Part>);
 
-fn process(args: &Args) -> Result<()> {
-    let (machine, parts) = read_input(args.input.as_str())?;
-    if args.debug {
-        let sorted = machine.sort_workflows();
-        for workflow in sorted.iter() {
-            println!("{}", workflow);
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        let (machine, parts) = read_input(path)?;
+        if log_enabled!(target: "parse", Level::Debug) {
+            for workflow in machine.sort_workflows().iter() {
+                debug!(target: "parse", "{}", workflow);
+            }
         }
+        Ok((machine, parts))
     }
 
-    let ans1 = machine.apply_all(&parts)?;
-    println!("ans1 = {}", ans1);
-
-    let ans2 = machine.count_possibilities(args.debug);
-    println!("ans2 = {}", ans2);
-
-    Ok(())
-}
+    fn part1(&self, (machine, parts): &Self::Input) -> Result<Answer> {
+        Ok(machine.apply_all(parts)?.into())
+    }
 
-fn main() {
-    let args = Args::parse();
-    match process(&args) {
-        Ok(_) => (),
-        Err(error) => panic!("{:?}", error),
-    };
+    fn part2(&self, (machine, _): &Self::Input) -> Result<Answer> {
+        Ok(machine.count_possibilities().into())
+    }
 }
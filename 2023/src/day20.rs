@@ -0,0 +1,453 @@
+use advent::checkpoint;
+use advent::common::dot::Digraph;
+use advent::common::output;
+use advent::common::vcd::VcdWriter;
+use advent::common::{read_lines, StrIterator};
+use advent::solution::{Answer, Solution};
+use advent::{debug, trace};
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use num::integer::lcm;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::fs::File;
+use std::io::BufWriter;
+use std::iter;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+enum SignalLevel {
+    Low,
+    High,
+}
+
+impl Display for SignalLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SignalLevel::Low => "low",
+                SignalLevel::High => "high",
+            }
+        )
+    }
+}
+
+#[derive(Debug)]
+struct Signal {
+    sender: String,
+    receiver: String,
+    level: SignalLevel,
+}
+
+impl Display for Signal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -{}-> {}", self.sender, self.level, self.receiver,)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop {
+        on: bool,
+    },
+    Conjunction {
+        states: HashMap<String, SignalLevel>,
+    },
+    Output,
+    Rx,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Module {
+    typ: ModuleType,
+    name: String,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+}
+
+impl FromStr for Module {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pos = s
+            .find(" -> ")
+            .with_context(|| format!("module missing ->: {:?}", s))?;
+        let lhs = &s[..pos];
+        let rhs = &s[pos + 4..];
+        let c = s
+            .chars()
+            .nth(0)
+            .with_context(|| format!("module lhs is empty: {:?}", s))?;
+        let (typ, name) = match c {
+            '%' => (ModuleType::FlipFlop { on: false }, &lhs[1..]),
+            '&' => (
+                ModuleType::Conjunction {
+                    states: HashMap::new(),
+                },
+                &lhs[1..],
+            ),
+            _ => (ModuleType::Broadcaster, lhs),
+        };
+        let name = name.to_owned();
+        let inputs = Vec::new();
+        let outputs = rhs.split(", ").map(|s| s.to_owned()).collect_vec();
+        Ok(Module {
+            typ,
+            name,
+            inputs,
+            outputs,
+        })
+    }
+}
+
+// Fills in the input fields as the inverse of the output fields.
+fn initialize_inputs(modules: &mut HashMap<String, Module>) {
+    let mut index: HashMap<String, Vec<String>> = HashMap::new();
+    for (_, module) in modules.iter() {
+        for output in module.outputs.iter() {
+            if !index.contains_key(output) {
+                index.insert(output.clone(), Vec::new());
+            }
+            let v = index.get_mut(output).expect("just checked");
+            v.push(module.name.clone());
+        }
+    }
+
+    for (_, module) in modules.iter_mut() {
+        if let Some(inputs) = index.get(&module.name) {
+            for input in inputs {
+                module.inputs.push(input.clone());
+            }
+        }
+    }
+}
+
+fn initialize_conjunctions(modules: &mut HashMap<String, Module>) {
+    for (_, module) in modules.iter_mut() {
+        if let ModuleType::Conjunction { states } = &mut module.typ {
+            for input in module.inputs.iter() {
+                states.insert(input.clone(), SignalLevel::Low);
+            }
+        }
+    }
+}
+
+fn read_input(path: &str) -> Result<HashMap<String, Module>> {
+    let mut map = HashMap::new();
+    let modules: Vec<Module> = read_lines(path)?.parse_all()?;
+    for module in modules {
+        map.insert(module.name.clone(), module);
+    }
+    map.insert(
+        "output".to_owned(),
+        Module {
+            typ: ModuleType::Output,
+            name: "output".to_owned(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        },
+    );
+    map.insert(
+        "rx".to_owned(),
+        Module {
+            typ: ModuleType::Rx,
+            name: "rx".to_owned(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        },
+    );
+    initialize_inputs(&mut map);
+    initialize_conjunctions(&mut map);
+    Ok(map)
+}
+
+// In the trace, each button press gets this many time steps, one per pulse.
+const PRESS_TIME: u64 = 10_000;
+
+// A waveform trace with a wire for each module's output.
+type Trace = VcdWriter<BufWriter<File>>;
+
+// Starts a trace, if one was asked for.
+fn start_trace(modules: &HashMap<String, Module>) -> Result<Option<Trace>> {
+    let Some(path) = &output::settings().vcd else {
+        return Ok(None);
+    };
+    let wires: Vec<&str> = iter::once("button")
+        .chain(
+            modules
+                .values()
+                .filter(|module| !module.outputs.is_empty())
+                .map(|module| module.name.as_str())
+                .sorted(),
+        )
+        .collect();
+    debug!("tracing {} wires to {}", wires.len(), path.display());
+    Ok(Some(VcdWriter::create(path, "day20", &wires)?))
+}
+
+// Pushes the button for the `press`th time and returns the number of low and high pulses sent.
+// Every pulse is also passed to `on_signal` and recorded in `trace` as it's delivered.
+fn run_once(
+    modules: &mut HashMap<String, Module>,
+    press: u64,
+    mut trace: Option<&mut Trace>,
+    mut on_signal: impl FnMut(&Signal) -> Result<()>,
+) -> Result<(u64, u64)> {
+    let mut low = 0;
+    let mut high = 0;
+    let mut pulse = 0;
+    let mut q = VecDeque::new();
+    q.push_back(Signal {
+        sender: "button".to_owned(),
+        receiver: "broadcaster".to_owned(),
+        level: SignalLevel::Low,
+    });
+    while let Some(signal) = q.pop_front() {
+        on_signal(&signal)?;
+        trace!("{}", signal);
+        if let Some(trace) = trace.as_deref_mut() {
+            if pulse == PRESS_TIME {
+                bail!("press {} sent more than {} pulses", press, PRESS_TIME);
+            }
+            let high = matches!(signal.level, SignalLevel::High);
+            trace.change(press * PRESS_TIME + pulse, &signal.sender, high)?;
+            pulse += 1;
+        }
+        match &signal.level {
+            SignalLevel::Low => low += 1,
+            SignalLevel::High => high += 1,
+        }
+        let module = modules
+            .get_mut(&signal.receiver)
+            .context(format!("output module {} not in input", signal.receiver))?;
+        let output_level = match &mut module.typ {
+            ModuleType::FlipFlop { on } => {
+                if let SignalLevel::Low = signal.level {
+                    *on = !*on;
+                    if *on {
+                        SignalLevel::High
+                    } else {
+                        SignalLevel::Low
+                    }
+                } else {
+                    continue;
+                }
+            }
+            ModuleType::Conjunction { states } => {
+                states.insert(signal.sender.clone(), signal.level);
+                let mut all_high = true;
+                for (_, level) in states.iter() {
+                    if let SignalLevel::Low = level {
+                        all_high = false;
+                        break;
+                    }
+                }
+                if all_high {
+                    SignalLevel::Low
+                } else {
+                    SignalLevel::High
+                }
+            }
+            ModuleType::Broadcaster => signal.level,
+            ModuleType::Output => {
+                continue;
+            }
+            ModuleType::Rx => {
+                continue;
+            }
+        };
+        for output in module.outputs.iter() {
+            q.push_back(Signal {
+                sender: signal.receiver.clone(),
+                receiver: output.clone(),
+                level: output_level,
+            });
+        }
+    }
+    Ok((low, high))
+}
+
+// How many times part 1 pushes the button.
+const PRESSES: u64 = 1000;
+
+// How many low and high pulses went from one module to another.
+type EdgeCounts = HashMap<(String, String), (u64, u64)>;
+
+// Also writes the graph of the modules if one was asked for, since with counts on,
+// its edges are labelled with the pulses they carried during these presses.
+fn part1(modules: &HashMap<String, Module>) -> Result<u64> {
+    let settings = output::settings();
+    let mut state = modules.clone();
+    let mut counts = EdgeCounts::new();
+    let mut trace = start_trace(modules)?;
+    let mut low = 0;
+    let mut high = 0;
+    for press in 1..=PRESSES {
+        let (l, h) = run_once(&mut state, press, trace.as_mut(), |signal| {
+            if settings.dot_counts {
+                let count = counts
+                    .entry((signal.sender.clone(), signal.receiver.clone()))
+                    .or_default();
+                match signal.level {
+                    SignalLevel::Low => count.0 += 1,
+                    SignalLevel::High => count.1 += 1,
+                }
+            }
+            Ok(())
+        })?;
+        low += l;
+        high += h;
+    }
+    if let Some(trace) = trace {
+        trace.finish()?;
+    }
+    if let Some(path) = &settings.dot {
+        write_graph(modules, path, &counts)?;
+    }
+    Ok(low * high)
+}
+
+// Gives up on part 2 if the cycles are longer than this.
+const MAX_PRESSES: u64 = 1_000_000;
+
+// How far part 2 has gotten, so it can be saved to a checkpoint.
+#[derive(Serialize, Deserialize)]
+struct Presses {
+    modules: HashMap<String, Module>,
+    presses: u64,
+    periods: HashMap<String, u64>,
+}
+
+// Looking at the input data, rx is fed by a single conjunction, which has to send low.
+// Each of that conjunction's inputs is an independent graph that sends high periodically,
+// so rx gets a low pulse at the lcm of their periods.
+fn part2(modules: &HashMap<String, Module>) -> Result<u64> {
+    let rx = modules.get("rx").expect("rx is always added");
+    let feeder = match rx.inputs.as_slice() {
+        [feeder] => feeder.clone(),
+        _ => bail!("expected exactly one input to rx, got {:?}", rx.inputs),
+    };
+    let feeder_inputs = modules
+        .get(&feeder)
+        .expect("inputs are modules")
+        .inputs
+        .clone();
+
+    let mut state = checkpoint::resume("presses")?.unwrap_or_else(|| Presses {
+        modules: modules.clone(),
+        presses: 0,
+        periods: HashMap::new(),
+    });
+    let mut trace = start_trace(modules)?;
+    while state.periods.len() < feeder_inputs.len() {
+        checkpoint::check("presses", || &state)?;
+        if state.presses >= MAX_PRESSES {
+            bail!(
+                "inputs to {} did not all fire in {} presses",
+                feeder,
+                state.presses
+            );
+        }
+        state.presses += 1;
+        let i = state.presses;
+        let mut senders = Vec::new();
+        run_once(&mut state.modules, i, trace.as_mut(), |signal| {
+            if matches!(signal.level, SignalLevel::High) && signal.receiver == feeder {
+                senders.push(signal.sender.clone());
+            }
+            Ok(())
+        })?;
+        for sender in senders {
+            state.periods.entry(sender).or_insert_with_key(|sender| {
+                debug!("{} first sent high on press {}", sender, i);
+                i
+            });
+        }
+    }
+    if let Some(trace) = trace {
+        trace.finish()?;
+    }
+    Ok(state.periods.values().fold(1, |ans, &n| lcm(ans, n)))
+}
+
+// Draws the modules as a graph, replacing the old make_20_graph.py.
+// Each type of module gets its own shape, and edges found in `counts` are labelled.
+fn write_graph(modules: &HashMap<String, Module>, path: &Path, counts: &EdgeCounts) -> Result<()> {
+    let mut graph = Digraph::new("day20");
+    graph.node("button", &[("shape", "plaintext")]);
+    let mut edges = vec![("button", "broadcaster")];
+    for (name, module) in modules.iter().sorted_by_key(|(name, _)| *name) {
+        let (label, shape) = match module.typ {
+            ModuleType::Broadcaster => (name.clone(), "doubleoctagon"),
+            ModuleType::FlipFlop { .. } => (format!("%{}", name), "box"),
+            ModuleType::Conjunction { .. } => (format!("&{}", name), "invtrapezium"),
+            ModuleType::Output => (name.clone(), "ellipse"),
+            ModuleType::Rx => (name.clone(), "doublecircle"),
+        };
+        // rx and output are always added, so only draw them if something feeds them.
+        if module.inputs.is_empty() && module.outputs.is_empty() {
+            continue;
+        }
+        graph.node(name, &[("label", &label), ("shape", shape)]);
+        edges.extend(
+            module
+                .outputs
+                .iter()
+                .map(|output| (name.as_str(), output.as_str())),
+        );
+    }
+    for (from, to) in edges {
+        match counts.get(&(from.to_owned(), to.to_owned())) {
+            Some((low, high)) => {
+                let label = format!("{} low, {} high", low, high);
+                graph.edge(from, to, &[("label", &label)]);
+            }
+            None => graph.edge(from, to, &[]),
+        }
+    }
+    graph.write(path)?;
+    debug!("wrote {}", path.display());
+    Ok(())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = HashMap<String, Module>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        let modules = read_input(path)?;
+        for module in modules.values() {
+            debug!("{:?}", module);
+        }
+        Ok(modules)
+    }
+
+    fn part1(&self, modules: &Self::Input) -> Result<Answer> {
+        Ok(part1(modules)?.into())
+    }
+
+    fn part2(&self, modules: &Self::Input) -> Result<Answer> {
+        Ok(part2(modules)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let input = Day20.parse("data/20/sample1.txt").unwrap();
+        assert_eq!(Day20.part1(&input).unwrap(), 32000000.into());
+        let input = Day20.parse("data/20/sample2.txt").unwrap();
+        assert_eq!(Day20.part1(&input).unwrap(), 11687500.into());
+        // Nothing feeds rx in the examples.
+        assert!(Day20.part2(&input).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use advent::checkpoint;
+use advent::common::{read_grid_with_markers, Array2D, InputSpec, Topology};
+use advent::solution::{Answer, Solution};
+use anyhow::{anyhow, Result};
+use indicatif::ProgressBar;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default)]
+pub struct Plot {
+    current: bool,
+    previous: bool,
+}
+
+pub struct Garden {
+    grid: Array2D<Option<Plot>>,
+    start: (usize, usize),
+    steps: i32,
+}
+
+// The number of steps the elf takes, unless the input spec says otherwise.
+const STEPS: i32 = 64;
+
+// Reads the garden from an input spec such as "sample.txt?steps=6".
+fn read_input(spec: &str) -> Result<Garden> {
+    let spec = InputSpec::parse(spec)?;
+    let (grid, markers) =
+        read_grid_with_markers(spec.path, &["start"], |c, (r, col), markers| match c {
+            '.' => Ok(Some(Plot::default())),
+            'S' => {
+                markers.mark("start", r, col);
+                Ok(Some(Plot::default()))
+            }
+            '#' => Ok(None),
+            _ => Err(anyhow!("invalid char: {}", c)),
+        })?;
+    Ok(Garden {
+        grid,
+        start: markers["start"],
+        steps: spec.param("steps", STEPS)?,
+    })
+}
+
+fn is_reachable(grid: &Array2D<Option<Plot>>, r: usize, c: usize, topology: Topology) -> bool {
+    grid.neighbors4(r, c, topology)
+        .any(|(_, _, other)| other.as_ref().is_some_and(|other| other.previous))
+}
+
+// How far count_reachable has gotten, so it can be saved to a checkpoint.
+#[derive(Serialize, Deserialize)]
+struct Steps {
+    taken: i32,
+    reached: Vec<(usize, usize)>,
+}
+
+// Part 1 stays inside the garden, and part 2 wraps around its edges as if it were tiled.
+fn count_reachable(garden: &Garden, topology: Topology) -> Result<usize> {
+    let iterations = garden.steps;
+    let key = format!("{} steps ({:?})", iterations, topology);
+    let steps = checkpoint::resume(&key)?.unwrap_or_else(|| Steps {
+        taken: 0,
+        reached: vec![garden.start],
+    });
+    let mut grid = garden.grid.clone();
+    for &pos in steps.reached.iter() {
+        if let Some(plot) = grid[pos].as_mut() {
+            plot.current = true;
+        }
+    }
+    let progress = ProgressBar::new(iterations as u64);
+    progress.set_position(steps.taken as u64);
+    for taken in steps.taken..iterations {
+        checkpoint::check(&key, || Steps {
+            taken,
+            reached: grid
+                .enumerate()
+                .filter(|(_, _, plot)| plot.as_ref().is_some_and(|plot| plot.current))
+                .map(|(r, c, _)| (r, c))
+                .collect(),
+        })?;
+        for (_, _, plot) in grid.enumerate_mut() {
+            if let Some(plot) = plot.as_mut() {
+                plot.previous = plot.current;
+                plot.current = false;
+            }
+        }
+
+        for (r, c) in grid.positions().collect::<Vec<_>>() {
+            if grid[(r, c)].as_ref().is_none() {
+                continue;
+            }
+            if is_reachable(&grid, r, c, topology) {
+                if let Some(plot) = grid[(r, c)].as_mut() {
+                    plot.current = true;
+                }
+            }
+        }
+
+        progress.inc(1);
+    }
+    progress.finish();
+
+    Ok(grid
+        .enumerate()
+        .filter(|(_, _, plot)| plot.as_ref().is_some_and(|plot| plot.current))
+        .count())
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, garden: &Self::Input) -> Result<Answer> {
+        Ok(count_reachable(garden, Topology::Bounded)?.into())
+    }
+
+    fn part2(&self, garden: &Self::Input) -> Result<Answer> {
+        Ok(count_reachable(garden, Topology::Torus)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day21.parse("data/21/sample.txt?steps=6").unwrap();
+        assert_eq!(Day21.part1(&input).unwrap(), 16.into());
+        assert_eq!(Day21.part2(&input).unwrap(), 16.into());
+
+        // In 64 steps, wrapping around the edges reaches all 81 plots.
+        let input = Day21.parse("data/21/sample.txt").unwrap();
+        assert_eq!(Day21.part1(&input).unwrap(), 42.into());
+        assert_eq!(Day21.part2(&input).unwrap(), 81.into());
+    }
+}
@@ -1,230 +1,47 @@
-pub mod common {
-    use std::fs::File;
-    use std::io::{self, BufRead, BufReader};
-    use std::ops::{Index, IndexMut};
-    use std::str::FromStr;
-    use thiserror::Error;
-
-    #[derive(Error, Debug)]
-    pub enum CommonError {
-        #[error("unable to open file {path:?}")]
-        FileNotFound { source: io::Error, path: String },
-
-        #[error("unable to parse {line:?}")]
-        ParseError {
-            source: Box<dyn std::error::Error + Send + Sync>,
-            line: String,
-        },
-
-        #[error("mismatched rows: got {got:?}; expected {expected:?}")]
-        MismatchedRowsError { got: usize, expected: usize },
-
-        #[error(transparent)]
-        InnerError(#[from] Box<dyn std::error::Error + Send + Sync>),
-    }
-
-    pub fn split_on(s: &str, c: char) -> Option<(&str, &str)> {
-        match s.find(c) {
-            Some(i) => {
-                let (s1, s2) = s.split_at(i);
-                let s2 = &s2[1..];
-                Some((s1, s2))
-            }
-            None => None,
-        }
-    }
-
-    pub struct LineReader {
-        f: BufReader<File>,
-    }
-
-    impl Iterator for LineReader {
-        type Item = String;
-
-        fn next(&mut self) -> Option<String> {
-            loop {
-                let mut line = String::new();
-                let n = self.f.read_line(&mut line).unwrap();
-                // TODO: Figure out how to do this without making a copy.
-                let line = line.trim();
-                if line == "" {
-                    if n == 0 {
-                        return None;
-                    }
-                    continue;
-                }
-                return Some(line.to_owned());
-            }
-        }
-    }
-
-    // Skips blank lines.
-    pub fn read_lines(path: &str) -> Result<LineReader, CommonError> {
-        match File::open(path) {
-            Ok(f) => Ok(LineReader {
-                f: BufReader::new(f),
-            }),
-            Err(e) => Err(CommonError::FileNotFound {
-                source: e,
-                path: path.to_owned(),
-            }),
-        }
-    }
-
-    pub struct Array2D<T> {
-        data: Vec<T>,
-        rows: usize,
-        cols: usize,
-    }
-
-    impl<T> Array2D<T> {
-        pub fn rows(&self) -> usize {
-            self.rows
-        }
-
-        pub fn columns(&self) -> usize {
-            self.cols
-        }
-
-        fn get_index(&self, row: usize, col: usize) -> Option<usize> {
-            if row >= self.rows || col >= self.cols {
-                None
-            } else {
-                Some(row * self.cols + col)
-            }
-        }
-
-        pub fn get(&self, row: usize, col: usize) -> Option<&T> {
-            self.get_index(row, col)
-                .map(|i| self.data.get(i).expect("checked bounds"))
-        }
-
-        pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
-            self.get_index(row, col)
-                .map(|i| self.data.get_mut(i).expect("checked bounds"))
-        }
-
-        pub fn from_rows<I: IntoIterator<Item = Vec<T>>>(it: I) -> Result<Self, CommonError> {
-            let mut data = Vec::new();
-            let mut cols = None;
-            let mut rows = 0usize;
-
-            for row in it {
-                match cols {
-                    Some(n) => {
-                        if row.len() != n {
-                            return Err(CommonError::MismatchedRowsError {
-                                got: n,
-                                expected: row.len(),
-                            });
-                        }
-                    }
-                    None => cols = Some(row.len()),
-                }
-                for r in row {
-                    data.push(r);
-                }
-                rows += 1;
-            }
-
-            let cols = cols.unwrap_or(0usize);
-            if data.len() != rows * cols {
-                panic!(
-                    "data.len() should be {}*{}={}, but is {}",
-                    rows,
-                    cols,
-                    rows * cols,
-                    data.len()
-                );
-            }
-            Ok(Array2D { data, rows, cols })
-        }
-    }
-
-    impl<T> Index<(usize, usize)> for Array2D<T> {
-        type Output = T;
-
-        fn index(&self, index: (usize, usize)) -> &T {
-            self.get(index.0, index.1)
-                .unwrap_or_else(|| panic!("index out of bounds: ({}, {})", index.0, index.1))
-        }
-    }
-
-    impl<T> IndexMut<(usize, usize)> for Array2D<T> {
-        fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
-            self.get_mut(index.0, index.1)
-                .unwrap_or_else(|| panic!("index out of bounds: ({}, {})", index.0, index.1))
-        }
-    }
-
-    impl<T> TryFrom<Vec<Vec<T>>> for Array2D<T> {
-        type Error = CommonError;
-
-        fn try_from(value: Vec<Vec<T>>) -> Result<Self, CommonError> {
-            Array2D::from_rows(value)
-        }
-    }
-
-    pub fn read_grid<T, E>(
-        path: &str,
-        f: fn(char) -> Result<T, E>,
-    ) -> Result<Array2D<T>, CommonError>
-    where
-        E: Into<Box<dyn std::error::Error + Send + Sync>>,
-    {
-        let mut v = Vec::new();
-        for line in read_lines(path)? {
-            let mut row = Vec::new();
-            for c in line.chars() {
-                match f(c) {
-                    Ok(item) => row.push(item),
-                    Err(e) => {
-                        return Err(CommonError::InnerError(e.into()));
-                    }
-                }
-            }
-            v.push(row);
-        }
-        Ok(Array2D::from_rows(v)?)
-    }
-
-    // Parses every item in the given iterator using FromStr.
-    pub fn parse_all<'a, T, I, S>(items: I) -> Result<Vec<T>, CommonError>
-    where
-        T: FromStr,
-        S: AsRef<str>,
-        I: Iterator<Item = S>,
-        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
-    {
-        let mut v = Vec::new();
-        for part in items {
-            let part = part.as_ref().trim();
-            match part.parse::<T>() {
-                Ok(n) => v.push(n),
-                Err(e) => {
-                    return Err(CommonError::ParseError {
-                        line: part.to_owned(),
-                        source: e.into(),
-                    });
-                }
-            }
-        }
-        Ok(v)
-    }
-
-    // Helper methods for iterators.
-    pub trait StrIterator: Iterator {
-        // Parses every item in the given iterator using FromStr.
-        fn parse_all<'a, S, T>(self) -> Result<Vec<T>, CommonError>
-        where
-            S: AsRef<str>,
-            Self: Iterator<Item = S> + Sized,
-            T: FromStr,
-            T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
-        {
-            crate::common::parse_all(self)
-        }
-    }
-
-    impl<T> StrIterator for T where T: Iterator {}
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+
+use advent::solution::Registry;
+
+pub fn register(registry: &mut Registry) {
+    registry.add(2023, 1, day01::Day01);
+    registry.add(2023, 2, day02::Day02);
+    registry.add(2023, 3, day03::Day03);
+    registry.add(2023, 4, day04::Day04);
+    registry.add(2023, 5, day05::Day05);
+    registry.add(2023, 6, day06::Day06);
+    registry.add(2023, 7, day07::Day07);
+    registry.add(2023, 8, day08::Day08);
+    registry.add(2023, 9, day09::Day09);
+    registry.add(2023, 10, day10::Day10);
+    registry.add(2023, 11, day11::Day11);
+    registry.add(2023, 12, day12::Day12);
+    registry.add(2023, 13, day13::Day13);
+    registry.add(2023, 14, day14::Day14);
+    registry.add(2023, 15, day15::Day15);
+    registry.add(2023, 16, day16::Day16);
+    registry.add(2023, 17, day17::Day17);
+    registry.add(2023, 18, day18::Day18);
+    registry.add(2023, 19, day19::Day19);
+    registry.add(2023, 20, day20::Day20);
+    registry.add(2023, 21, day21::Day21);
 }
@@ -0,0 +1,143 @@
+use crate::solution::{take_counters, Answer, Part, Solver};
+use crate::verify::{read_answers, ANSWERS_FILE};
+use anyhow::{anyhow, Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Every input in a day's data directory: the .txt files other than the answers manifest, by name.
+pub fn input_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("unable to list {}", dir.display()))? {
+        let path = entry?.path();
+        let is_input = path.extension().is_some_and(|ext| ext == "txt")
+            && path.file_name().is_some_and(|name| name != ANSWERS_FILE);
+        if is_input && path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// The number of threads to use when none is asked for.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+// Calls `f` on every item using up to `threads` threads, and returns the results in order.
+pub fn parallel_map<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().expect("no worker panicked")[i] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .expect("no worker panicked")
+        .into_iter()
+        .map(|r| r.expect("every item was processed"))
+        .collect()
+}
+
+// How one part did on one input file.
+#[derive(Debug)]
+pub struct FileResult {
+    // The file name, relative to the data directory.
+    pub file: String,
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+    pub counters: BTreeMap<&'static str, u64>,
+    // The answer recorded in answers.txt, if there is one.
+    pub expected: Option<String>,
+}
+
+impl FileResult {
+    // None if there's no recorded answer to compare against.
+    pub fn matches(&self) -> Option<bool> {
+        let expected = self.expected.as_ref()?;
+        Some(
+            self.answer
+                .as_ref()
+                .is_ok_and(|answer| answer.to_string() == *expected),
+        )
+    }
+}
+
+// Runs `parts` against every input file in `dir`, one file per job.
+// Failures are recorded in the results rather than stopping the other files.
+pub fn run_all(
+    solver: &dyn Solver,
+    dir: &Path,
+    parts: &[Part],
+    threads: usize,
+) -> Result<Vec<FileResult>> {
+    let files = input_files(dir)?;
+    let manifest = dir.join(ANSWERS_FILE);
+    let expected = if manifest.exists() {
+        read_answers(&manifest.to_string_lossy())?
+    } else {
+        Vec::new()
+    };
+
+    let per_file = parallel_map(&files, threads, |path| {
+        let file = path
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        let parsed = solver.parse(&path.to_string_lossy());
+        parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match &parsed {
+                    Ok(input) => solver.solve(input.as_ref(), part),
+                    Err(err) => Err(anyhow!("{:#}", err)),
+                };
+                FileResult {
+                    elapsed: start.elapsed(),
+                    counters: take_counters(),
+                    expected: expected
+                        .iter()
+                        .find(|e| e.file == file && e.part == part)
+                        .map(|e| e.answer.clone()),
+                    file: file.clone(),
+                    part,
+                    answer,
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+    Ok(per_file.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_map_keeps_order() {
+        let items: Vec<u64> = (0..100).collect();
+        let squares = parallel_map(&items, 4, |&n| n * n);
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert!(parallel_map(&[] as &[u64], 4, |&n| n).is_empty());
+    }
+}
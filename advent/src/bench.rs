@@ -0,0 +1,336 @@
+use crate::common::read_lines;
+use crate::solution::{Part, Solver};
+use anyhow::{bail, Context, Result};
+use std::fmt::{self, Display};
+use std::fs;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+// What is being timed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::Solve(Part::One)),
+            "part2" => Ok(Phase::Solve(Part::Two)),
+            _ => bail!("invalid phase: {:?}", s),
+        }
+    }
+}
+
+// Summary statistics over several runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    // Panics if there are no samples.
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Stats {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+// The timing of one phase of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub year: u32,
+    pub day: u32,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+impl Timing {
+    // One line of a results file, with durations in nanoseconds.
+    fn to_line(&self) -> String {
+        let s = &self.stats;
+        format!(
+            "{} {} {} {} {} {} {} {}",
+            self.year,
+            self.day,
+            self.phase,
+            s.runs,
+            s.min.as_nanos(),
+            s.median.as_nanos(),
+            s.mean.as_nanos(),
+            s.stddev.as_nanos()
+        )
+    }
+
+    fn from_line(line: &str) -> Result<Self> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [year, day, phase, runs, min, median, mean, stddev] = fields[..] else {
+            bail!(
+                "expected \"<year> <day> <phase> <runs> <min> <median> <mean> <stddev>\", got {:?}",
+                line
+            );
+        };
+        let nanos = |s: &str| -> Result<Duration> {
+            Ok(Duration::from_nanos(s.parse().with_context(|| {
+                format!("invalid duration {:?} in {:?}", s, line)
+            })?))
+        };
+        Ok(Timing {
+            year: year.parse()?,
+            day: day.parse()?,
+            phase: phase.parse()?,
+            stats: Stats {
+                runs: runs.parse()?,
+                min: nanos(min)?,
+                median: nanos(median)?,
+                mean: nanos(mean)?,
+                stddev: nanos(stddev)?,
+            },
+        })
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = &self.stats;
+        write!(
+            f,
+            "{} day {:02} {:<5}  min {:>10.3?}  median {:>10.3?}  mean {:>10.3?}  stddev {:>10.3?}",
+            self.year, self.day, self.phase, s.min, s.median, s.mean, s.stddev
+        )
+    }
+}
+
+// Parses the input and solves each part `runs` times, timing each phase separately.
+// Every run has to give the same answers, in case a solution depends on state it shouldn't.
+pub fn bench_day(
+    year: u32,
+    day: u32,
+    solver: &dyn Solver,
+    path: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Vec<Timing>> {
+    if runs == 0 {
+        bail!("benchmarking needs at least one run");
+    }
+    let mut parse_times = Vec::new();
+    let mut part_times = vec![Vec::new(); parts.len()];
+    let mut answers = Vec::new();
+    for run in 0..runs {
+        let start = Instant::now();
+        let parsed = solver.parse(path)?;
+        parse_times.push(start.elapsed());
+
+        for (i, &part) in parts.iter().enumerate() {
+            let start = Instant::now();
+            let answer = solver.solve(parsed.as_ref(), part)?;
+            part_times[i].push(start.elapsed());
+            if run == 0 {
+                answers.push(answer);
+            } else if answer != answers[i] {
+                bail!(
+                    "part {} answered {} on run {}, but {} on run 1",
+                    part,
+                    answer,
+                    run + 1,
+                    answers[i]
+                );
+            }
+        }
+    }
+
+    let timing = |phase, samples: &[Duration]| Timing {
+        year,
+        day,
+        phase,
+        stats: Stats::new(samples),
+    };
+    let mut timings = vec![timing(Phase::Parse, &parse_times)];
+    for (&part, samples) in parts.iter().zip(&part_times) {
+        timings.push(timing(Phase::Solve(part), samples));
+    }
+    Ok(timings)
+}
+
+// Writes timings in a format that read_results can load for comparing later.
+pub fn write_results(path: &str, timings: &[Timing]) -> Result<()> {
+    let mut text = String::from("# year day phase runs min median mean stddev (ns)\n");
+    for timing in timings {
+        text.push_str(&timing.to_line());
+        text.push('\n');
+    }
+    fs::write(path, text).with_context(|| format!("unable to write {}", path))
+}
+
+// Reads a results file. Lines starting with # are comments.
+pub fn read_results(path: &str) -> Result<Vec<Timing>> {
+    let mut v = Vec::new();
+    for line in read_lines(path)? {
+        if line.starts_with('#') {
+            continue;
+        }
+        v.push(
+            Timing::from_line(&line)
+                .with_context(|| format!("in {}:{}", line.path, line.number))?,
+        );
+    }
+    Ok(v)
+}
+
+// How a timing compares to the same phase in an earlier run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub timing: Timing,
+    pub baseline: Duration,
+    // How much slower the median is, as a percentage. Negative is faster.
+    pub percent: f64,
+    pub regressed: bool,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = if self.regressed { "SLOWER" } else { "ok    " };
+        write!(
+            f,
+            "{} {} day {:02} {:<5}  median {:>10.3?}  was {:>10.3?}  {:+.1}%",
+            label,
+            self.timing.year,
+            self.timing.day,
+            self.timing.phase,
+            self.timing.stats.median,
+            self.baseline,
+            self.percent
+        )
+    }
+}
+
+// Compares medians against a baseline. Phases the baseline doesn't have are left out.
+// A phase has regressed if its median got more than `threshold` percent slower.
+pub fn compare(timings: &[Timing], baseline: &[Timing], threshold: f64) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for timing in timings {
+        let Some(old) = baseline
+            .iter()
+            .find(|b| (b.year, b.day, b.phase) == (timing.year, timing.day, timing.phase))
+        else {
+            continue;
+        };
+        let old = old.stats.median;
+        let new = timing.stats.median;
+        let percent = if old.is_zero() {
+            0.0
+        } else {
+            (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0
+        };
+        changes.push(Change {
+            timing: timing.clone(),
+            baseline: old,
+            percent,
+            regressed: percent > threshold,
+        });
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn timing(phase: Phase, median: Duration) -> Timing {
+        Timing {
+            year: 2023,
+            day: 18,
+            phase,
+            stats: Stats {
+                runs: 1,
+                min: median,
+                median,
+                mean: median,
+                stddev: Duration::ZERO,
+            },
+        }
+    }
+
+    #[test]
+    fn computes_stats() {
+        let stats = Stats::new(&[ms(4), ms(1), ms(3), ms(2)]);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+
+        let stats = Stats::new(&[ms(5), ms(1), ms(9)]);
+        assert_eq!(stats.median, ms(5));
+    }
+
+    #[test]
+    fn round_trips_lines() {
+        let t = Timing {
+            stats: Stats::new(&[ms(4), ms(1), ms(3)]),
+            ..timing(Phase::Solve(Part::Two), ms(0))
+        };
+        assert_eq!(Timing::from_line(&t.to_line()).unwrap(), t);
+        assert!(Timing::from_line("2023 18 part3 1 1 1 1 0").is_err());
+    }
+
+    #[test]
+    fn flags_regressions() {
+        let baseline = [
+            timing(Phase::Parse, ms(10)),
+            timing(Phase::Solve(Part::One), ms(10)),
+        ];
+        let timings = [
+            timing(Phase::Parse, ms(11)),
+            timing(Phase::Solve(Part::One), ms(13)),
+            timing(Phase::Solve(Part::Two), ms(100)),
+        ];
+        let changes = compare(&timings, &baseline, 20.0);
+        assert_eq!(changes.len(), 2);
+        assert!(!changes[0].regressed);
+        assert!(changes[1].regressed);
+        assert!((changes[1].percent - 30.0).abs() < 1e-6);
+    }
+}
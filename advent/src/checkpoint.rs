@@ -0,0 +1,154 @@
+use anyhow::{bail, Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use thiserror::Error;
+
+// Set by Ctrl-C. Long-running solvers poll it through `check`.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+// Returned by `check` once the solver's state has been saved.
+#[derive(Error, Debug)]
+#[error("interrupted {run}; saved a checkpoint to {}", path.display())]
+pub struct Interrupted {
+    pub run: String,
+    pub path: PathBuf,
+}
+
+// What's written to the checkpoint file.
+#[derive(Serialize, Deserialize)]
+pub struct Saved {
+    // Which run this is from, e.g. "2023 day 20 part 2 (2023/data/20/input.txt)".
+    pub run: String,
+    // Which of the solver's loops saved the state, e.g. "trip 2".
+    pub key: String,
+    pub state: serde_json::Value,
+}
+
+struct Session {
+    path: PathBuf,
+    run: String,
+    resume: Option<Saved>,
+}
+
+thread_local! {
+    static SESSION: RefCell<Option<Session>> = const { RefCell::new(None) };
+}
+
+// Makes the first Ctrl-C stop long-running solvers at their next checkpoint.
+// A second one exits right away.
+pub fn handle_ctrl_c() -> Result<()> {
+    ctrlc::set_handler(|| {
+        if INTERRUPTED.swap(true, Ordering::SeqCst) {
+            process::exit(130);
+        }
+        eprintln!("interrupted; saving a checkpoint (press Ctrl-C again to quit)");
+    })
+    .context("unable to handle Ctrl-C")
+}
+
+pub fn interrupted() -> bool {
+    INTERRUPTED.load(Ordering::Relaxed)
+}
+
+pub fn load(path: &Path) -> Result<Saved> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("unable to read checkpoint {}", path.display()))?;
+    serde_json::from_str(&text)
+        .with_context(|| format!("unable to parse checkpoint {}", path.display()))
+}
+
+// Saves checkpoints for `run` to `path` until `end` is called.
+// If `resume` is from the same run, solvers will pick up from its state.
+pub fn begin(path: &Path, run: &str, resume: Option<Saved>) {
+    let resume = resume.filter(|saved| saved.run == run);
+    SESSION.with(|s| {
+        *s.borrow_mut() = Some(Session {
+            path: path.to_owned(),
+            run: run.to_owned(),
+            resume,
+        })
+    });
+}
+
+pub fn end() {
+    SESSION.with(|s| *s.borrow_mut() = None);
+}
+
+// Returns the state saved under `key` if this run is resuming one.
+// The state is only handed out once, so a solver that loops over keys starts the rest fresh.
+pub fn resume<T: DeserializeOwned>(key: &str) -> Result<Option<T>> {
+    let saved = SESSION.with(|s| {
+        let mut s = s.borrow_mut();
+        let session = s.as_mut()?;
+        match &session.resume {
+            Some(saved) if saved.key == key => session.resume.take(),
+            _ => None,
+        }
+    });
+    let Some(saved) = saved else {
+        return Ok(None);
+    };
+    let state = serde_json::from_value(saved.state).with_context(|| {
+        format!(
+            "checkpoint for {} has the wrong shape for {key:?}",
+            saved.run
+        )
+    })?;
+    Ok(Some(state))
+}
+
+// If Ctrl-C was pressed, saves `state()` under `key` and returns an Interrupted error.
+// Cheap enough to call on every step of a loop.
+pub fn check<T: Serialize>(key: &str, state: impl FnOnce() -> T) -> Result<()> {
+    if !interrupted() {
+        return Ok(());
+    }
+    let Some((path, run)) = SESSION.with(|s| {
+        s.borrow()
+            .as_ref()
+            .map(|session| (session.path.clone(), session.run.clone()))
+    }) else {
+        bail!("interrupted");
+    };
+    let saved = Saved {
+        run: run.clone(),
+        key: key.to_owned(),
+        state: serde_json::to_value(state()).context("unable to serialize checkpoint")?,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let text = serde_json::to_string(&saved)?;
+    fs::write(&path, text)
+        .with_context(|| format!("unable to write checkpoint {}", path.display()))?;
+    Err(Interrupted { run, path }.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resume_matches_run_and_key() {
+        let saved = || Saved {
+            run: "2023 day 20 part 2".to_owned(),
+            key: "presses".to_owned(),
+            state: serde_json::json!([1, 2, 3]),
+        };
+
+        begin(Path::new("unused"), "2023 day 20 part 1", Some(saved()));
+        assert_eq!(resume::<Vec<u32>>("presses").unwrap(), None);
+        end();
+
+        begin(Path::new("unused"), "2023 day 20 part 2", Some(saved()));
+        assert_eq!(resume::<Vec<u32>>("other").unwrap(), None);
+        assert_eq!(resume::<Vec<u32>>("presses").unwrap(), Some(vec![1, 2, 3]));
+        assert_eq!(resume::<Vec<u32>>("presses").unwrap(), None);
+        end();
+    }
+}
@@ -0,0 +1,219 @@
+//! Simulations played back as text in the terminal.
+
+use super::output;
+use super::render::Color;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::env;
+use std::io::{self, BufWriter, Write};
+use std::iter;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Returns the animation to play simulations with, or None if animations weren't asked for.
+pub fn animation() -> Option<Animation> {
+    output::settings().fps.map(Animation::new)
+}
+
+// Calls `step` until it returns false, and returns how many steps it took.
+// If animations are on, plays a frame drawn by `frame` after each step, with `colors` for its tiles.
+pub fn simulate<S>(
+    state: &mut S,
+    colors: &[(char, Color)],
+    mut step: impl FnMut(&mut S) -> bool,
+    frame: impl Fn(&S) -> Frame,
+) -> Result<usize> {
+    let Some(animation) = animation() else {
+        let mut steps = 0;
+        while step(state) {
+            steps += 1;
+        }
+        return Ok(steps);
+    };
+    let animation = colors.iter().fold(animation, |animation, &(c, color)| {
+        animation.color(c, color)
+    });
+    animation.play(iter::from_fn(|| step(state).then(|| frame(state))))
+}
+
+// One picture in an animation, as lines of text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub text: String,
+    // The (line, column) to keep on screen when the frame is bigger than the terminal.
+    pub focus: Option<(usize, usize)>,
+}
+
+impl Frame {
+    pub fn new(text: String) -> Self {
+        Frame { text, focus: None }
+    }
+
+    pub fn focus(mut self, line: usize, column: usize) -> Self {
+        self.focus = Some((line, column));
+        self
+    }
+}
+
+impl From<String> for Frame {
+    fn from(text: String) -> Self {
+        Frame::new(text)
+    }
+}
+
+// Plays frames in a terminal, redrawing each one over the last.
+// Frames bigger than the terminal are clipped to the part around their focus.
+#[derive(Debug, Clone)]
+pub struct Animation {
+    fps: f64,
+    columns: usize,
+    rows: usize,
+    colors: HashMap<char, Color>,
+}
+
+// Reads a terminal dimension that the shell exported, such as COLUMNS.
+fn terminal_size(var: &str, default: usize) -> usize {
+    env::var(var)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or(default)
+}
+
+impl Animation {
+    pub fn new(fps: f64) -> Self {
+        Animation {
+            fps,
+            columns: terminal_size("COLUMNS", 80),
+            rows: terminal_size("LINES", 24),
+            colors: HashMap::new(),
+        }
+    }
+
+    pub fn size(mut self, columns: usize, rows: usize) -> Self {
+        self.columns = columns;
+        self.rows = rows;
+        self
+    }
+
+    // Draws every `c` in this color.
+    pub fn color(mut self, c: char, color: Color) -> Self {
+        self.colors.insert(c, color);
+        self
+    }
+
+    // Draws every frame to stderr, and returns how many there were.
+    pub fn play<F: Into<Frame>>(&self, frames: impl IntoIterator<Item = F>) -> Result<usize> {
+        let mut out = BufWriter::new(io::stderr().lock());
+        // Clear the screen and hide the cursor.
+        write!(out, "\x1b[2J\x1b[?25l")?;
+        let frame_time = Duration::from_secs_f64(1.0 / self.fps.max(0.001));
+        let mut next = Instant::now();
+        let mut count = 0;
+        let result = frames.into_iter().try_for_each(|frame| {
+            count += 1;
+            self.draw(&mut out, &frame.into(), count)?;
+            out.flush()?;
+            next += frame_time;
+            thread::sleep(next.saturating_duration_since(Instant::now()));
+            io::Result::Ok(())
+        });
+        // Show the cursor again, even if drawing failed.
+        write!(out, "\x1b[?25h")?;
+        out.flush()?;
+        result?;
+        Ok(count)
+    }
+
+    // Draws one frame from the top left corner of the terminal, with a status line under it.
+    fn draw(&self, out: &mut impl Write, frame: &Frame, number: usize) -> io::Result<()> {
+        let lines: Vec<&str> = frame.text.lines().collect();
+        let height = self.rows.saturating_sub(1).max(1);
+        let width = self.columns.max(1);
+        let widest = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let (top, left) = match frame.focus {
+            Some((line, column)) => (
+                line.saturating_sub(height / 2)
+                    .min(lines.len().saturating_sub(height)),
+                column
+                    .saturating_sub(width / 2)
+                    .min(widest.saturating_sub(width)),
+            ),
+            None => (0, 0),
+        };
+
+        write!(out, "\x1b[H")?;
+        for line in lines.iter().skip(top).take(height) {
+            let mut current = None;
+            for c in line.chars().skip(left).take(width) {
+                let color = self.colors.get(&c);
+                if color != current {
+                    match color {
+                        Some(color) => {
+                            write!(out, "\x1b[38;2;{};{};{}m", color.r, color.g, color.b)?
+                        }
+                        None => write!(out, "\x1b[0m")?,
+                    }
+                    current = color;
+                }
+                write!(out, "{}", c)?;
+            }
+            // Reset the color and clear whatever the last frame left on the line.
+            writeln!(out, "\x1b[0m\x1b[K")?;
+        }
+        write!(out, "\x1b[0m\x1b[Kframe {}", number)?;
+        if lines.len() > height || widest > width {
+            write!(
+                out,
+                "  (lines {}-{} of {}, columns {}-{} of {})",
+                top + 1,
+                (top + height).min(lines.len()),
+                lines.len(),
+                left + 1,
+                (left + width).min(widest),
+                widest
+            )?;
+        }
+        // Clear anything below, in case the last frame was taller.
+        write!(out, "\x1b[J")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(animation: &Animation, frame: Frame) -> String {
+        let mut out = Vec::new();
+        animation.draw(&mut out, &frame, 1).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn clips_around_focus() {
+        let animation = Animation::new(30.0).size(4, 3);
+        let text = "abcdefgh\nijklmnop\nqrstuvwx\n".to_owned();
+        let out = draw(&animation, Frame::new(text).focus(2, 7));
+        assert!(out.contains("mnop\x1b[0m\x1b[K\n"), "{:?}", out);
+        assert!(out.contains("uvwx\x1b[0m\x1b[K\n"), "{:?}", out);
+        assert!(!out.contains("efgh"), "{:?}", out);
+        assert!(
+            out.contains("lines 2-3 of 3, columns 5-8 of 8"),
+            "{:?}",
+            out
+        );
+    }
+
+    #[test]
+    fn colors_tiles() {
+        let animation = Animation::new(30.0)
+            .size(80, 24)
+            .color('#', Color::rgb(1, 2, 3));
+        let out = draw(&animation, Frame::new(".##.".to_owned()));
+        assert!(
+            out.contains(".\x1b[38;2;1;2;3m##\x1b[0m.\x1b[0m"),
+            "{:?}",
+            out
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// How to notice that a sequence of states has started repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycleStrategy {
+    // Remembers the key of every state seen. Finds the cycle as soon as it first repeats.
+    HashMap,
+    // Brent's algorithm. Uses constant memory, but steps through the sequence a few more times.
+    Brent,
+}
+
+// Where a sequence of states starts repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    // The number of steps before the first state that is part of the cycle.
+    pub prefix: u64,
+    // The number of steps until a state repeats.
+    pub period: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct FastForward<S> {
+    // The state after all the steps.
+    pub state: S,
+    // The cycle that was skipped over, if one was found before running out of steps.
+    pub cycle: Option<Cycle>,
+}
+
+fn advance<S>(mut state: S, steps: u64, step: &mut impl FnMut(&S) -> S) -> S {
+    for _ in 0..steps {
+        state = step(&state);
+    }
+    state
+}
+
+// Applies `step` to `start` n times, skipping ahead once a state repeats.
+pub fn fast_forward<S>(
+    start: S,
+    n: u64,
+    step: impl FnMut(&S) -> S,
+    strategy: CycleStrategy,
+) -> FastForward<S>
+where
+    S: Clone + Eq + Hash,
+{
+    fast_forward_by_key(start, n, step, S::clone, strategy)
+}
+
+// Like fast_forward, but two states count as the same if they have the same key.
+// The state returned is found by stepping from the first state with a repeated key.
+pub fn fast_forward_by_key<S, K>(
+    start: S,
+    n: u64,
+    mut step: impl FnMut(&S) -> S,
+    key: impl Fn(&S) -> K,
+    strategy: CycleStrategy,
+) -> FastForward<S>
+where
+    S: Clone,
+    K: Eq + Hash,
+{
+    let cycle = match strategy {
+        CycleStrategy::HashMap => find_with_map(&start, n, &mut step, &key),
+        CycleStrategy::Brent => find_with_brent(&start, n, &mut step, &key),
+    };
+    match cycle {
+        // The cycle can only have been found if there were enough steps to reach its end.
+        Ok((cycle, first)) => {
+            let remaining = (n - cycle.prefix) % cycle.period;
+            FastForward {
+                state: advance(first, remaining, &mut step),
+                cycle: Some(cycle),
+            }
+        }
+        Err(state) => FastForward { state, cycle: None },
+    }
+}
+
+// Returns the cycle and the first state in it, or the state after n steps if there's no repeat.
+fn find_with_map<S: Clone, K: Eq + Hash>(
+    start: &S,
+    n: u64,
+    step: &mut impl FnMut(&S) -> S,
+    key: &impl Fn(&S) -> K,
+) -> Result<(Cycle, S), S> {
+    let mut seen: HashMap<K, u64> = HashMap::new();
+    let mut state = start.clone();
+    for i in 0..n {
+        if let Some(&prev) = seen.get(&key(&state)) {
+            let cycle = Cycle {
+                prefix: prev,
+                period: i - prev,
+            };
+            // Rather than remember every state, step forward from the start again.
+            let first = advance(start.clone(), prev, step);
+            return Ok((cycle, first));
+        }
+        seen.insert(key(&state), i);
+        state = step(&state);
+    }
+    Err(state)
+}
+
+fn find_with_brent<S: Clone, K: Eq + Hash>(
+    start: &S,
+    n: u64,
+    step: &mut impl FnMut(&S) -> S,
+    key: &impl Fn(&S) -> K,
+) -> Result<(Cycle, S), S> {
+    if n == 0 {
+        return Err(start.clone());
+    }
+
+    // First, find the period by moving the tortoise to the hare at every power of 2.
+    let mut power = 1u64;
+    let mut period = 1u64;
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    let mut taken = 1u64;
+    while key(&tortoise) != key(&hare) {
+        if taken == n {
+            return Err(hare);
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+        taken += 1;
+    }
+
+    // Then, find the prefix by starting a period apart and moving together until they meet.
+    let mut tortoise = start.clone();
+    let mut hare = advance(start.clone(), period, step);
+    let mut prefix = 0u64;
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Ok((Cycle { prefix, period }, tortoise))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, ...
+    fn step(n: &u64) -> u64 {
+        if *n == 6 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn finds_cycle() {
+        for strategy in [CycleStrategy::HashMap, CycleStrategy::Brent] {
+            let result = fast_forward(0u64, 1_000_000_000_000, step, strategy);
+            assert_eq!(
+                result.cycle,
+                Some(Cycle {
+                    prefix: 3,
+                    period: 4
+                })
+            );
+            assert_eq!(result.state, 3 + (1_000_000_000_000 - 3) % 4);
+        }
+    }
+
+    #[test]
+    fn stops_before_cycle() {
+        for strategy in [CycleStrategy::HashMap, CycleStrategy::Brent] {
+            for n in 0..5 {
+                let result = fast_forward(0u64, n, step, strategy);
+                assert_eq!(result.state, n);
+            }
+        }
+    }
+
+    #[test]
+    fn matches_by_key() {
+        // The count keeps growing, but the key only looks at the phase.
+        let result = fast_forward_by_key(
+            (0u64, 0u64),
+            10,
+            |&(phase, count)| ((phase + 1) % 3, count + 1),
+            |&(phase, _)| phase,
+            CycleStrategy::HashMap,
+        );
+        assert_eq!(
+            result.cycle,
+            Some(Cycle {
+                prefix: 0,
+                period: 3
+            })
+        );
+        assert_eq!(result.state, (1, 1));
+    }
+}
@@ -0,0 +1,85 @@
+//! Graphs in Graphviz's DOT language.
+
+use anyhow::{Context, Result};
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+
+// A directed graph in Graphviz's DOT language, built up a statement at a time.
+#[derive(Debug, Clone)]
+pub struct Digraph {
+    name: String,
+    statements: Vec<String>,
+}
+
+impl Digraph {
+    pub fn new(name: &str) -> Self {
+        Digraph {
+            name: name.to_owned(),
+            statements: Vec::new(),
+        }
+    }
+
+    // Adds a node with attributes such as ("shape", "box").
+    pub fn node(&mut self, id: &str, attrs: &[(&str, &str)]) {
+        self.statements
+            .push(format!("{}{}", quote(id), attributes(attrs)));
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, attrs: &[(&str, &str)]) {
+        self.statements.push(format!(
+            "{} -> {}{}",
+            quote(from),
+            quote(to),
+            attributes(attrs)
+        ));
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string())
+            .with_context(|| format!("unable to write {}", path.display()))
+    }
+}
+
+impl Display for Digraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph {} {{", quote(&self.name))?;
+        for statement in self.statements.iter() {
+            writeln!(f, "  {};", statement)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+// Quotes an ID, so that names like "&inv" don't have to be valid DOT identifiers.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn attributes(attrs: &[(&str, &str)]) -> String {
+    if attrs.is_empty() {
+        return String::new();
+    }
+    let attrs: Vec<String> = attrs
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, quote(value)))
+        .collect();
+    format!(" [{}]", attrs.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_dot() {
+        let mut graph = Digraph::new("day20");
+        graph.node("inv", &[("label", "&inv"), ("shape", "box")]);
+        graph.node("say \"hi\"", &[]);
+        graph.edge("inv", "say \"hi\"", &[("label", "3")]);
+        assert_eq!(
+            graph.to_string(),
+            "digraph \"day20\" {\n  \"inv\" [label=\"&inv\", shape=\"box\"];\n  \"say \\\"hi\\\"\";\n  \"inv\" -> \"say \\\"hi\\\"\" [label=\"3\"];\n}\n"
+        );
+    }
+}
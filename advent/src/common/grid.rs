@@ -0,0 +1,531 @@
+use super::CommonError;
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+// How positions past the edge of a grid are treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    // There is nothing past the edge.
+    Bounded,
+    // The edges wrap around, so the grid behaves like an infinitely tiled plane.
+    Torus,
+}
+
+// Offsets to the orthogonal neighbors, in the order N, W, S, E.
+const NEIGHBORS4: [(i64, i64); 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+
+// Offsets to all neighbors, including diagonals, in reading order.
+const NEIGHBORS8: [(i64, i64); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Array2D<T> {
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Array2D<T> {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.cols
+    }
+
+    fn get_index(&self, row: usize, col: usize) -> Option<usize> {
+        if row >= self.rows || col >= self.cols {
+            None
+        } else {
+            Some(row * self.cols + col)
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.get_index(row, col)
+            .map(|i| self.data.get(i).expect("checked bounds"))
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.get_index(row, col)
+            .map(|i| self.data.get_mut(i).expect("checked bounds"))
+    }
+
+    // Returns the position (dr, dc) away from (row, col), if there is one.
+    pub fn offset(
+        &self,
+        row: usize,
+        col: usize,
+        dr: i64,
+        dc: i64,
+        topology: Topology,
+    ) -> Option<(usize, usize)> {
+        let r = row as i64 + dr;
+        let c = col as i64 + dc;
+        match topology {
+            Topology::Bounded => {
+                if r < 0 || c < 0 || r >= self.rows as i64 || c >= self.cols as i64 {
+                    None
+                } else {
+                    Some((r as usize, c as usize))
+                }
+            }
+            Topology::Torus => {
+                if self.rows == 0 || self.cols == 0 {
+                    None
+                } else {
+                    Some((
+                        r.rem_euclid(self.rows as i64) as usize,
+                        c.rem_euclid(self.cols as i64) as usize,
+                    ))
+                }
+            }
+        }
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        row: usize,
+        col: usize,
+        offsets: &'static [(i64, i64)],
+        topology: Topology,
+    ) -> impl Iterator<Item = (usize, usize, &'a T)> + 'a {
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            self.offset(row, col, dr, dc, topology)
+                .map(|(r, c)| (r, c, &self[(r, c)]))
+        })
+    }
+
+    // Iterates over the cells north, west, south, and east of (row, col).
+    // On a torus with fewer than 3 rows or columns, the same cell may appear twice.
+    pub fn neighbors4(
+        &self,
+        row: usize,
+        col: usize,
+        topology: Topology,
+    ) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.neighbors(row, col, &NEIGHBORS4, topology)
+    }
+
+    // Iterates over the 8 cells around (row, col), including diagonals.
+    pub fn neighbors8(
+        &self,
+        row: usize,
+        col: usize,
+        topology: Topology,
+    ) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.neighbors(row, col, &NEIGHBORS8, topology)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row out of bounds: {}", row);
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column out of bounds: {}", col);
+        self.data.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(|r| self.row(r))
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|c| self.column(c))
+    }
+
+    // Iterates over every (row, col) in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |r| (0..cols).map(move |c| (r, c)))
+    }
+
+    // Iterates over every (row, col, cell) in row-major order.
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        let cols = self.cols;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(i, t)| (i / cols, i % cols, t))
+    }
+
+    pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        let cols = self.cols;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, t)| (i / cols, i % cols, t))
+    }
+
+    // Rearranges the cells into a grid of the given size.
+    // `source` maps each new (row, col) to the old position it should come from.
+    fn permute(
+        &mut self,
+        rows: usize,
+        cols: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) {
+        let old_cols = self.cols;
+        let mut old: Vec<Option<T>> = std::mem::take(&mut self.data)
+            .into_iter()
+            .map(Some)
+            .collect();
+        let mut data = Vec::with_capacity(old.len());
+        for r in 0..rows {
+            for c in 0..cols {
+                let (r0, c0) = source(r, c);
+                data.push(
+                    old[r0 * old_cols + c0]
+                        .take()
+                        .expect("each cell is moved once"),
+                );
+            }
+        }
+        self.data = data;
+        self.rows = rows;
+        self.cols = cols;
+    }
+
+    // Swaps rows and columns, so that (row, col) moves to (col, row).
+    pub fn transpose(&mut self) {
+        self.permute(self.cols, self.rows, |r, c| (c, r));
+    }
+
+    // Rotates the grid 90 degrees, so that the west edge becomes the north edge.
+    pub fn rotate_clockwise(&mut self) {
+        let rows = self.rows;
+        self.permute(self.cols, self.rows, |r, c| (rows - 1 - c, r));
+    }
+
+    // Rotates the grid 90 degrees, so that the east edge becomes the north edge.
+    pub fn rotate_counterclockwise(&mut self) {
+        let cols = self.cols;
+        self.permute(self.cols, self.rows, |r, c| (c, cols - 1 - r));
+    }
+
+    // Mirrors the grid left to right.
+    pub fn flip_horizontal(&mut self) {
+        if self.cols > 0 {
+            self.data
+                .chunks_mut(self.cols)
+                .for_each(|row| row.reverse());
+        }
+    }
+
+    // Mirrors the grid top to bottom.
+    pub fn flip_vertical(&mut self) {
+        for r in 0..self.rows / 2 {
+            let (top, bottom) = self.data.split_at_mut((self.rows - 1 - r) * self.cols);
+            top[r * self.cols..(r + 1) * self.cols].swap_with_slice(&mut bottom[..self.cols]);
+        }
+    }
+
+    // Returns a view of the `rows` x `cols` rectangle with its top-left corner at (row, col).
+    pub fn view(&self, row: usize, col: usize, rows: usize, cols: usize) -> SubGrid<'_, T> {
+        assert!(
+            row + rows <= self.rows && col + cols <= self.cols,
+            "view out of bounds: {}x{} at ({}, {})",
+            rows,
+            cols,
+            row,
+            col
+        );
+        SubGrid {
+            grid: self,
+            row,
+            col,
+            rows,
+            cols,
+        }
+    }
+
+    // Draws the grid one character per cell, with a newline after each row.
+    pub fn render_with(&self, f: impl Fn(&T) -> char) -> String {
+        render(self.iter_rows(), f)
+    }
+
+    pub fn from_rows<I: IntoIterator<Item = Vec<T>>>(it: I) -> Result<Self, CommonError> {
+        let mut data = Vec::new();
+        let mut cols = None;
+        let mut rows = 0usize;
+
+        for row in it {
+            match cols {
+                Some(n) => {
+                    if row.len() != n {
+                        return Err(CommonError::MismatchedRowsError {
+                            got: row.len(),
+                            expected: n,
+                            location: None,
+                        });
+                    }
+                }
+                None => cols = Some(row.len()),
+            }
+            for r in row {
+                data.push(r);
+            }
+            rows += 1;
+        }
+
+        let cols = cols.unwrap_or(0usize);
+        if data.len() != rows * cols {
+            panic!(
+                "data.len() should be {}*{}={}, but is {}",
+                rows,
+                cols,
+                rows * cols,
+                data.len()
+            );
+        }
+        Ok(Array2D { data, rows, cols })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Array2D<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T {
+        self.get(index.0, index.1)
+            .unwrap_or_else(|| panic!("index out of bounds: ({}, {})", index.0, index.1))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Array2D<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        self.get_mut(index.0, index.1)
+            .unwrap_or_else(|| panic!("index out of bounds: ({}, {})", index.0, index.1))
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Array2D<T> {
+    type Error = CommonError;
+
+    fn try_from(value: Vec<Vec<T>>) -> Result<Self, CommonError> {
+        Array2D::from_rows(value)
+    }
+}
+
+impl<T: Display> Display for Array2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(f, self.iter_rows())
+    }
+}
+
+// A borrowed rectangle within an Array2D, indexed relative to its own corner.
+#[derive(Debug)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Array2D<T>,
+    row: usize,
+    col: usize,
+    rows: usize,
+    cols: usize,
+}
+
+// Derived Clone would needlessly require T: Clone.
+impl<'a, T> Clone for SubGrid<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for SubGrid<'a, T> {}
+
+impl<'a, T> SubGrid<'a, T> {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.rows || col >= self.cols {
+            None
+        } else {
+            self.grid.get(self.row + row, self.col + col)
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.rows, "row out of bounds: {}", row);
+        &self.grid.row(self.row + row)[self.col..self.col + self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let view = *self;
+        (0..self.rows).map(move |r| view.row(r))
+    }
+
+    // Iterates over every (row, col, cell) in row-major order.
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, usize, &'a T)> {
+        self.iter_rows()
+            .enumerate()
+            .flat_map(|(r, row)| row.iter().enumerate().map(move |(c, t)| (r, c, t)))
+    }
+
+    pub fn to_array(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        Array2D::from_rows(self.iter_rows().map(|row| row.to_vec()))
+            .expect("rows are the same length")
+    }
+
+    pub fn render_with(&self, f: impl Fn(&T) -> char) -> String {
+        render(self.iter_rows(), f)
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for SubGrid<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T {
+        self.get(index.0, index.1)
+            .unwrap_or_else(|| panic!("index out of bounds: ({}, {})", index.0, index.1))
+    }
+}
+
+impl<'a, T: Display> Display for SubGrid<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(f, self.iter_rows())
+    }
+}
+
+fn render<'a, T: 'a>(rows: impl Iterator<Item = &'a [T]>, f: impl Fn(&T) -> char) -> String {
+    let mut s = String::new();
+    for row in rows {
+        s.extend(row.iter().map(&f));
+        s.push('\n');
+    }
+    s
+}
+
+fn write_rows<'a, T: Display + 'a>(
+    f: &mut fmt::Formatter<'_>,
+    rows: impl Iterator<Item = &'a [T]>,
+) -> fmt::Result {
+    for row in rows {
+        for t in row {
+            write!(f, "{}", t)?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Array2D<i32> {
+        Array2D::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap()
+    }
+
+    fn values<'a>(it: impl Iterator<Item = (usize, usize, &'a i32)>) -> Vec<i32> {
+        it.map(|(_, _, &n)| n).collect()
+    }
+
+    #[test]
+    fn bounded_neighbors() {
+        let g = grid();
+        assert_eq!(
+            values(g.neighbors4(1, 1, Topology::Bounded)),
+            vec![2, 4, 8, 6]
+        );
+        assert_eq!(values(g.neighbors4(0, 0, Topology::Bounded)), vec![4, 2]);
+        assert_eq!(values(g.neighbors8(0, 0, Topology::Bounded)), vec![2, 4, 5]);
+        assert_eq!(
+            values(g.neighbors8(1, 1, Topology::Bounded)),
+            vec![1, 2, 3, 4, 6, 7, 8, 9]
+        );
+    }
+
+    #[test]
+    fn torus_neighbors() {
+        let g = grid();
+        assert_eq!(
+            values(g.neighbors4(0, 0, Topology::Torus)),
+            vec![7, 3, 4, 2]
+        );
+        assert_eq!(
+            values(g.neighbors8(2, 2, Topology::Torus)),
+            vec![5, 6, 4, 8, 7, 2, 3, 1]
+        );
+        assert_eq!(g.offset(0, 0, -4, 7, Topology::Torus), Some((2, 1)));
+    }
+
+    #[test]
+    fn neighbors_include_positions() {
+        let g = grid();
+        let v: Vec<_> = g.neighbors4(2, 0, Topology::Bounded).collect();
+        assert_eq!(v, vec![(1, 0, &4), (2, 1, &8)]);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let g = grid();
+        assert_eq!(g.row(1), &[4, 5, 6]);
+        assert_eq!(g.column(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+        assert_eq!(g.iter_rows().count(), 3);
+        let cols: Vec<Vec<i32>> = g.iter_columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(cols[0], vec![1, 4, 7]);
+    }
+
+    #[test]
+    fn positions_and_enumerate() {
+        let g = Array2D::from_rows(vec![vec!['a', 'b'], vec!['c', 'd']]).unwrap();
+        let p: Vec<_> = g.positions().collect();
+        assert_eq!(p, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+        let e: Vec<_> = g.enumerate().collect();
+        assert_eq!(e[2], (1, 0, &'c'));
+    }
+
+    fn chars(rows: &[&str]) -> Array2D<char> {
+        Array2D::from_rows(rows.iter().map(|r| r.chars().collect())).unwrap()
+    }
+
+    #[test]
+    fn transforms() {
+        let mut g = chars(&["abc", "def"]);
+        g.transpose();
+        assert_eq!(g.to_string(), "ad\nbe\ncf\n");
+
+        let mut g = chars(&["abc", "def"]);
+        g.rotate_clockwise();
+        assert_eq!(g.to_string(), "da\neb\nfc\n");
+        g.rotate_counterclockwise();
+        assert_eq!(g.to_string(), "abc\ndef\n");
+
+        g.flip_horizontal();
+        assert_eq!(g.to_string(), "cba\nfed\n");
+        g.flip_vertical();
+        assert_eq!(g.to_string(), "fed\ncba\n");
+    }
+
+    #[test]
+    fn views() {
+        let g = grid();
+        let v = g.view(1, 1, 2, 2);
+        assert_eq!(v[(0, 0)], 5);
+        assert_eq!(v.get(1, 1), Some(&9));
+        assert_eq!(v.get(2, 0), None);
+        assert_eq!(v.row(1), &[8, 9]);
+        assert_eq!(v.to_array().row(0), &[5, 6]);
+        assert_eq!(
+            v.render_with(|&n| char::from_digit(n as u32, 10).unwrap()),
+            "56\n89\n"
+        );
+    }
+}
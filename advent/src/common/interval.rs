@@ -0,0 +1,478 @@
+use num::PrimInt;
+
+// The half-open range of integers [start, end).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    // The interval [start, end], including the end.
+    pub fn inclusive(start: T, end: T) -> Self {
+        Interval::new(start, end + T::one())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let result = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!result.is_empty()).then_some(result)
+    }
+
+    // Splits into the parts before x and from x on.
+    pub fn split_at(&self, x: T) -> (Option<Self>, Option<Self>) {
+        let below = Interval::new(self.start, self.end.min(x));
+        let above = Interval::new(self.start.max(x), self.end);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+
+    // Returns the parts of this interval that aren't in other. There are at most two.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let mut v = Vec::new();
+        let before = Interval::new(self.start, self.end.min(other.start));
+        let after = Interval::new(self.start.max(other.end), self.end);
+        for part in [before, after] {
+            if !part.is_empty() {
+                v.push(part);
+            }
+        }
+        v
+    }
+
+    pub fn shifted(&self, by: T) -> Self {
+        Interval::new(self.start + by, self.end + by)
+    }
+}
+
+// A set of integers, stored as sorted, disjoint intervals that don't touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    // Sorts and merges the intervals so that they are disjoint and don't touch.
+    fn normalize(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|i| !i.is_empty());
+        intervals.sort_by_key(|i| i.start);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for i in intervals {
+            match merged.last_mut() {
+                Some(last) if i.start <= last.end => last.end = last.end.max(i.end),
+                _ => merged.push(i),
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = Self::normalize(intervals);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // The number of integers in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::zero(), |total, i| total + i.len())
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        match self.intervals.binary_search_by(|i| i.start.cmp(&x)) {
+            Ok(_) => true,
+            Err(0) => false,
+            Err(n) => self.intervals[n - 1].contains(x),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.iter().chain(other.iter()).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut v = Vec::new();
+        let (mut a, mut b) = (self.intervals.iter(), other.intervals.iter());
+        let (mut x, mut y) = (a.next(), b.next());
+        while let (Some(i), Some(j)) = (x, y) {
+            if let Some(k) = i.intersection(j) {
+                v.push(k);
+            }
+            // Whichever ends first can't overlap anything else in the other set.
+            if i.end < j.end {
+                x = a.next();
+            } else {
+                y = b.next();
+            }
+        }
+        IntervalSet { intervals: v }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut v = Vec::new();
+        for i in self.iter() {
+            let mut remaining = vec![*i];
+            for j in other.iter() {
+                if j.end <= i.start || j.start >= i.end {
+                    continue;
+                }
+                remaining = remaining.iter().flat_map(|r| r.difference(j)).collect();
+            }
+            v.extend(remaining);
+        }
+        Self::normalize(v)
+    }
+
+    // Splits into the parts before x and from x on.
+    pub fn split_at(&self, x: T) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        for i in self.iter() {
+            let (b, a) = i.split_at(x);
+            below.extend(b);
+            above.extend(a);
+        }
+        (
+            IntervalSet { intervals: below },
+            IntervalSet { intervals: above },
+        )
+    }
+
+    // Adds `by` to every number in the set.
+    pub fn shifted(&self, by: T) -> Self {
+        IntervalSet {
+            intervals: self.iter().map(|i| i.shifted(by)).collect(),
+        }
+    }
+}
+
+impl<T: PrimInt> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::normalize(vec![interval])
+    }
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().collect())
+    }
+}
+
+// An N-dimensional box, made of one interval along each axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region<T, const N: usize> {
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: PrimInt, const N: usize> Region<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Self {
+        Region { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|i| i.is_empty())
+    }
+
+    // The number of points in the region.
+    pub fn volume(&self) -> T {
+        self.axes.iter().fold(T::one(), |total, i| total * i.len())
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.axes.iter().zip(point).all(|(i, &x)| i.contains(x))
+    }
+
+    pub fn with_axis(&self, axis: usize, interval: Interval<T>) -> Self {
+        let mut copy = *self;
+        copy.axes[axis] = interval;
+        copy
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes;
+        for (axis, o) in axes.iter_mut().zip(other.axes.iter()) {
+            *axis = axis.intersection(o)?;
+        }
+        Some(Region { axes })
+    }
+
+    // Splits into the parts below x and from x on, along the given axis.
+    pub fn split_at(&self, axis: usize, x: T) -> (Option<Self>, Option<Self>) {
+        let (below, above) = self.axes[axis].split_at(x);
+        (
+            below.map(|i| self.with_axis(axis, i)),
+            above.map(|i| self.with_axis(axis, i)),
+        )
+    }
+
+    // Returns disjoint regions that cover the parts of this region that aren't in other.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.intersection(other).is_none() {
+            return vec![*self];
+        }
+        // Peel off the parts outside of other one axis at a time.
+        let mut v = Vec::new();
+        let mut rest = *self;
+        for axis in 0..N {
+            let o = other.axes[axis];
+            for part in rest.axes[axis].difference(&o) {
+                v.push(rest.with_axis(axis, part));
+            }
+            let inside = rest.axes[axis].intersection(&o).expect("regions intersect");
+            rest = rest.with_axis(axis, inside);
+        }
+        v
+    }
+}
+
+// A set of points, stored as disjoint regions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionSet<T, const N: usize> {
+    regions: Vec<Region<T, N>>,
+}
+
+impl<T: PrimInt, const N: usize> Default for RegionSet<T, N> {
+    fn default() -> Self {
+        RegionSet {
+            regions: Vec::new(),
+        }
+    }
+}
+
+impl<T: PrimInt, const N: usize> RegionSet<T, N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, region: Region<T, N>) {
+        let mut pieces = vec![region];
+        for existing in self.regions.iter() {
+            pieces = pieces.iter().flat_map(|p| p.difference(existing)).collect();
+        }
+        self.regions
+            .extend(pieces.into_iter().filter(|r| !r.is_empty()));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    // The number of points in the set.
+    pub fn volume(&self) -> T {
+        self.regions
+            .iter()
+            .fold(T::zero(), |total, r| total + r.volume())
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.regions.iter().any(|r| r.contains(point))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region<T, N>> {
+        self.regions.iter()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for r in other.iter() {
+            result.insert(*r);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut regions = Vec::new();
+        for a in self.iter() {
+            regions.extend(other.iter().filter_map(|b| a.intersection(b)));
+        }
+        RegionSet { regions }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut regions = Vec::new();
+        for r in self.iter() {
+            let mut pieces = vec![*r];
+            for o in other.iter() {
+                pieces = pieces.iter().flat_map(|p| p.difference(o)).collect();
+            }
+            regions.extend(pieces);
+        }
+        RegionSet { regions }
+    }
+
+    // Splits into the parts below x and from x on, along the given axis.
+    pub fn split_at(&self, axis: usize, x: T) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        for r in self.iter() {
+            let (b, a) = r.split_at(axis, x);
+            below.extend(b);
+            above.extend(a);
+        }
+        (RegionSet { regions: below }, RegionSet { regions: above })
+    }
+}
+
+impl<T: PrimInt, const N: usize> FromIterator<Region<T, N>> for RegionSet<T, N> {
+    fn from_iter<I: IntoIterator<Item = Region<T, N>>>(iter: I) -> Self {
+        let mut set = RegionSet::new();
+        for r in iter {
+            set.insert(r);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::HashSet;
+
+    // These check the set operations against plain HashSets of points on random inputs.
+
+    fn random_interval_set(rng: &mut StdRng) -> IntervalSet<i32> {
+        (0..rng.gen_range(0..5))
+            .map(|_| {
+                let start = rng.gen_range(-20..20);
+                Interval::new(start, start + rng.gen_range(0..10))
+            })
+            .collect()
+    }
+
+    fn points(set: &IntervalSet<i32>) -> HashSet<i32> {
+        set.iter().flat_map(|i| i.start..i.end).collect()
+    }
+
+    fn check_normalized(set: &IntervalSet<i32>) {
+        for (a, b) in set.iter().zip(set.iter().skip(1)) {
+            assert!(a.end < b.start, "{:?} is not normalized", set);
+        }
+        assert!(set.iter().all(|i| !i.is_empty()));
+    }
+
+    #[test]
+    fn interval_set_matches_points() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..500 {
+            let a = random_interval_set(&mut rng);
+            let b = random_interval_set(&mut rng);
+            let (pa, pb) = (points(&a), points(&b));
+
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let difference = a.difference(&b);
+            for set in [&a, &union, &intersection, &difference] {
+                check_normalized(set);
+            }
+            assert_eq!(points(&union), &pa | &pb);
+            assert_eq!(points(&intersection), &pa & &pb);
+            assert_eq!(points(&difference), &pa - &pb);
+            assert_eq!(a.len() as usize, pa.len());
+
+            let x = rng.gen_range(-25..35);
+            let (below, above) = a.split_at(x);
+            assert_eq!(
+                points(&below),
+                pa.iter().filter(|&&p| p < x).copied().collect()
+            );
+            assert_eq!(
+                points(&above),
+                pa.iter().filter(|&&p| p >= x).copied().collect()
+            );
+            assert_eq!(a.contains(x), pa.contains(&x));
+        }
+    }
+
+    fn random_region_set(rng: &mut StdRng) -> RegionSet<i32, 3> {
+        (0..rng.gen_range(0..4))
+            .map(|_| {
+                Region::new([(); 3].map(|_| {
+                    let start = rng.gen_range(0..6);
+                    Interval::new(start, start + rng.gen_range(0..5))
+                }))
+            })
+            .collect()
+    }
+
+    fn region_points(set: &RegionSet<i32, 3>) -> HashSet<[i32; 3]> {
+        let mut v = HashSet::new();
+        for r in set.iter() {
+            for x in r.axes[0].start..r.axes[0].end {
+                for y in r.axes[1].start..r.axes[1].end {
+                    for z in r.axes[2].start..r.axes[2].end {
+                        v.insert([x, y, z]);
+                    }
+                }
+            }
+        }
+        v
+    }
+
+    #[test]
+    fn region_set_matches_points() {
+        let mut rng = StdRng::seed_from_u64(19);
+        for _ in 0..300 {
+            let a = random_region_set(&mut rng);
+            let b = random_region_set(&mut rng);
+            let (pa, pb) = (region_points(&a), region_points(&b));
+
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let difference = a.difference(&b);
+            assert_eq!(region_points(&union), &pa | &pb);
+            assert_eq!(region_points(&intersection), &pa & &pb);
+            assert_eq!(region_points(&difference), &pa - &pb);
+            // The regions are disjoint, so the volumes add up to the number of points.
+            assert_eq!(a.volume() as usize, pa.len());
+            assert_eq!(union.volume() as usize, (&pa | &pb).len());
+
+            let axis = rng.gen_range(0..3);
+            let x = rng.gen_range(0..10);
+            let (below, above) = a.split_at(axis, x);
+            assert_eq!(below.volume() + above.volume(), a.volume());
+            assert!(region_points(&below).iter().all(|p| p[axis] < x));
+        }
+    }
+}
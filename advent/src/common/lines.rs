@@ -0,0 +1,303 @@
+use super::{parse_item, CommonError, Located, Location};
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+
+// The path that means "read from stdin" instead of a file.
+pub const STDIN_PATH: &str = "-";
+
+// One line of input, without its line ending.
+// It derefs to its text, and remembers where it came from for error messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub path: Arc<str>,
+    // Starts at 1.
+    pub number: usize,
+    pub text: String,
+    // The number of chars trimmed from the start of the text.
+    indent: usize,
+}
+
+impl Line {
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    // Removes whitespace from both ends, while keeping track of the original columns.
+    pub fn trimmed(mut self) -> Self {
+        let start = self.text.len() - self.text.trim_start().len();
+        self.indent += self.text[..start].chars().count();
+        self.text.drain(..start);
+        self.text.truncate(self.text.trim_end().len());
+        self
+    }
+
+    // Returns where `token`, which should be a slice of this line's text, appears.
+    pub fn locate(&self, token: &str) -> Location {
+        let mut location = Location::of(token, &self.text).in_file(&self.path, self.number);
+        location.column += self.indent;
+        location.source_line = format!("{}{}", " ".repeat(self.indent), self.text);
+        location
+    }
+
+    // Attaches this line's location to each of the given slices of it, so that
+    // parse_all can point at the exact token that failed.
+    pub fn tokens<'a, I>(&'a self, it: I) -> impl Iterator<Item = Token<'a>>
+    where
+        I: Iterator<Item = &'a str> + 'a,
+    {
+        it.map(move |text| self.token(text))
+    }
+
+    // Attaches this line's location to `text`, which should be a slice of it.
+    pub fn token<'a>(&'a self, text: &'a str) -> Token<'a> {
+        Token { line: self, text }
+    }
+}
+
+impl Deref for Line {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl AsRef<str> for Line {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl Located for Line {
+    fn location(&self) -> Option<Location> {
+        Some(self.locate(&self.text))
+    }
+}
+
+// A slice of a Line.
+#[derive(Debug, Clone, Copy)]
+pub struct Token<'a> {
+    line: &'a Line,
+    text: &'a str,
+}
+
+impl<'a> Token<'a> {
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    // Narrows the token to `text`, which should be a slice of it.
+    pub fn slice(&self, text: &'a str) -> Token<'a> {
+        self.line.token(text)
+    }
+
+    // Parses the token with FromStr. Errors point at it in the file.
+    pub fn parse<T>(&self) -> Result<T, CommonError>
+    where
+        T: FromStr,
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        parse_item(self, str::parse)
+    }
+
+    // Like parse, but with a function for things that aren't FromStr.
+    pub fn parse_with<T, E>(
+        &self,
+        parse: impl FnOnce(&str) -> Result<T, E>,
+    ) -> Result<T, CommonError>
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        parse_item(self, parse)
+    }
+}
+
+impl<'a> AsRef<str> for Token<'a> {
+    fn as_ref(&self) -> &str {
+        self.text
+    }
+}
+
+impl<'a> Located for Token<'a> {
+    fn location(&self) -> Option<Location> {
+        Some(self.line.locate(self.text))
+    }
+}
+
+// Reads every line of a file, including blank ones.
+pub struct Lines {
+    reader: Box<dyn BufRead>,
+    path: Arc<str>,
+    number: usize,
+    failed: bool,
+}
+
+impl Lines {
+    pub fn new(reader: Box<dyn BufRead>, path: &str) -> Self {
+        Lines {
+            reader,
+            path: path.into(),
+            number: 0,
+            failed: false,
+        }
+    }
+
+    // Groups the remaining lines into sections separated by one or more blank lines.
+    pub fn sections(self) -> Sections {
+        Sections { lines: self }
+    }
+}
+
+impl Iterator for Lines {
+    type Item = Result<Line, CommonError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Don't keep reading past an error, in case it would repeat forever.
+        if self.failed {
+            return None;
+        }
+        let mut text = String::new();
+        match self.reader.read_line(&mut text) {
+            Ok(0) => None,
+            Ok(_) => {
+                self.number += 1;
+                let len = text.trim_end_matches(['\n', '\r']).len();
+                text.truncate(len);
+                Some(Ok(Line {
+                    path: self.path.clone(),
+                    number: self.number,
+                    text,
+                    indent: 0,
+                }))
+            }
+            Err(source) => {
+                self.failed = true;
+                Some(Err(CommonError::ReadError {
+                    source,
+                    path: self.path.to_string(),
+                    line: self.number + 1,
+                }))
+            }
+        }
+    }
+}
+
+// Yields each run of non-blank lines.
+pub struct Sections {
+    lines: Lines,
+}
+
+impl Iterator for Sections {
+    type Item = Result<Vec<Line>, CommonError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut section = Vec::new();
+        for line in self.lines.by_ref() {
+            match line {
+                Ok(line) if line.is_blank() => {
+                    if !section.is_empty() {
+                        return Some(Ok(section));
+                    }
+                }
+                Ok(line) => section.push(line),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        (!section.is_empty()).then_some(Ok(section))
+    }
+}
+
+// Opens a file to read line by line. A path of "-" reads stdin.
+pub fn open_lines(path: &str) -> Result<Lines, CommonError> {
+    let reader: Box<dyn BufRead> = if path == STDIN_PATH {
+        Box::new(BufReader::new(io::stdin()))
+    } else {
+        match File::open(path) {
+            Ok(f) => Box::new(BufReader::new(f)),
+            Err(e) => {
+                return Err(CommonError::FileNotFound {
+                    source: e,
+                    path: path.to_owned(),
+                })
+            }
+        }
+    };
+    Ok(Lines::new(reader, path))
+}
+
+// Reads every section of a file at once.
+pub fn read_sections(path: &str) -> Result<Vec<Vec<Line>>, CommonError> {
+    open_lines(path)?.sections().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn lines(text: &'static str) -> Lines {
+        Lines::new(Box::new(Cursor::new(text)), "test")
+    }
+
+    #[test]
+    fn keeps_line_numbers() {
+        let v: Vec<Line> = lines("a\r\n\n  b\n").map(Result::unwrap).collect();
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[0].text, "a");
+        assert!(v[1].is_blank());
+        assert_eq!(v[2].number, 3);
+        assert_eq!(v[2].text, "  b");
+    }
+
+    #[test]
+    fn locates_tokens_after_trimming() {
+        let line = lines("  12 x4\n").next().unwrap().unwrap().trimmed();
+        assert_eq!(line.text, "12 x4");
+        let token = line.split_whitespace().nth(1).unwrap();
+        let location = line.locate(token);
+        assert_eq!(location.to_string(), "test:1:6");
+        assert_eq!(location.snippet(), "      12 x4\n         ^^");
+    }
+
+    #[test]
+    fn parses_tokens() {
+        let line = lines("move 12 to x4\n").next().unwrap().unwrap();
+        let token = line.token(&line[5..]);
+        assert_eq!(token.slice(&token.text()[..2]).parse::<i32>().unwrap(), 12);
+        let e = token.slice(&token.text()[6..]).parse::<i32>().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "test:1:12: unable to parse \"x4\"\n    move 12 to x4\n               ^^"
+        );
+    }
+
+    #[test]
+    fn splits_sections() {
+        let sections: Vec<Vec<String>> = lines("\na\nb\n\n\nc\n\n")
+            .sections()
+            .map(|s| s.unwrap().into_iter().map(|l| l.text).collect())
+            .collect();
+        assert_eq!(sections, vec![vec!["a", "b"], vec!["c"]]);
+    }
+
+    #[test]
+    fn reports_invalid_utf8() {
+        let mut it = Lines::new(Box::new(Cursor::new(b"ok\n\xff\xfe\nmore\n")), "test");
+        assert!(it.next().unwrap().is_ok());
+        assert!(matches!(
+            it.next(),
+            Some(Err(CommonError::ReadError { line: 2, .. }))
+        ));
+        assert!(it.next().is_none());
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::{self, Display};
+
+// Where some text came from in an input, for pointing at it in error messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub path: Option<String>,
+    // Starts at 1.
+    pub line: Option<usize>,
+    // Starts at 1, counted in chars.
+    pub column: usize,
+    // The number of chars to underline.
+    pub width: usize,
+    // The whole line the text was part of.
+    pub source_line: String,
+}
+
+impl Location {
+    // Finds `token` within `line`. If token isn't a slice of line, the whole line is used.
+    pub fn of(token: &str, line: &str) -> Self {
+        let start = token.as_ptr() as usize;
+        let base = line.as_ptr() as usize;
+        let (column, width) = if start >= base && start + token.len() <= base + line.len() {
+            let offset = start - base;
+            (line[..offset].chars().count() + 1, token.chars().count())
+        } else {
+            (1, line.chars().count())
+        };
+        Location {
+            path: None,
+            line: None,
+            column,
+            width,
+            source_line: line.to_owned(),
+        }
+    }
+
+    // Given that this is the location of `within`, returns the location of `token`, a slice of it.
+    pub fn narrow(&self, within: &str, token: &str) -> Location {
+        let inner = Location::of(token, within);
+        Location {
+            column: self.column + inner.column - 1,
+            width: inner.width,
+            ..self.clone()
+        }
+    }
+
+    pub fn in_file(mut self, path: &str, line: usize) -> Self {
+        self.path = Some(path.to_owned());
+        self.line = Some(line);
+        self
+    }
+
+    // The source line with a caret under the located text.
+    pub fn snippet(&self) -> String {
+        format!(
+            "    {}\n    {}{}",
+            self.source_line,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width.max(1))
+        )
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.path, self.line) {
+            (Some(path), Some(line)) => write!(f, "{}:{}:{}", path, line, self.column),
+            (None, Some(line)) => write!(f, "line {}, column {}", line, self.column),
+            _ => write!(f, "column {}", self.column),
+        }
+    }
+}
+
+// Formats an error message, pointing at the location if there is one.
+pub(super) fn describe(location: Option<&Location>, message: String) -> String {
+    match location {
+        Some(location) => format!("{}: {}\n{}", location, message, location.snippet()),
+        None => message,
+    }
+}
+
+// Text that may know where in an input it came from.
+pub trait Located: AsRef<str> {
+    fn location(&self) -> Option<Location> {
+        None
+    }
+}
+
+impl Located for str {}
+
+impl Located for String {}
+
+impl<T: Located + ?Sized> Located for &T {
+    fn location(&self) -> Option<Location> {
+        (**self).location()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_token() {
+        let line = "seeds: 79 1x 55";
+        let token = line.split_whitespace().nth(2).unwrap();
+        let location = Location::of(token, line).in_file("data/05/input.txt", 1);
+        assert_eq!(location.to_string(), "data/05/input.txt:1:11");
+        assert_eq!(location.snippet(), "    seeds: 79 1x 55\n              ^^");
+    }
+
+    #[test]
+    fn falls_back_to_whole_line() {
+        let location = Location::of("other", "abc");
+        assert_eq!(location.column, 1);
+        assert_eq!(location.width, 3);
+        assert_eq!(location.to_string(), "column 1");
+    }
+}
@@ -0,0 +1,255 @@
+use anyhow::{bail, Context, Result};
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, RwLock};
+
+// How much detail a log event has. Later levels are more verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// Parses a level name. "off" is None.
+fn parse_level(s: &str) -> Result<Option<Level>> {
+    Ok(Some(match s.to_ascii_lowercase().as_str() {
+        "off" => return Ok(None),
+        "error" => Level::Error,
+        "warn" => Level::Warn,
+        "info" => Level::Info,
+        "debug" => Level::Debug,
+        "trace" => Level::Trace,
+        _ => bail!("invalid log level: {:?}", s),
+    }))
+}
+
+// Which events to log, such as "info,day19=trace,day19::parse=off".
+// A bare level applies to every module that doesn't have its own.
+// A module name matches any module path that contains it as whole segments.
+// When more than one matches, the longest one wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    modules: Vec<(String, Option<Level>)>,
+}
+
+impl Filter {
+    pub fn level(level: Level) -> Self {
+        Filter {
+            default: Some(level),
+            modules: Vec::new(),
+        }
+    }
+
+    // The most verbose level logged for the given module path.
+    pub fn max_level(&self, module: &str) -> Option<Level> {
+        self.modules
+            .iter()
+            .filter(|(name, _)| contains_segments(module, name))
+            .max_by_key(|(name, _)| name.len())
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    // The most verbose level logged for any module.
+    fn max_overall(&self) -> Option<Level> {
+        self.modules
+            .iter()
+            .map(|&(_, level)| level)
+            .chain([self.default])
+            .max()
+            .flatten()
+    }
+}
+
+fn contains_segments(module: &str, name: &str) -> bool {
+    module == name
+        || module.starts_with(&format!("{}::", name))
+        || module.ends_with(&format!("::{}", name))
+        || module.contains(&format!("::{}::", name))
+}
+
+impl FromStr for Filter {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut filter = Filter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) => filter
+                    .modules
+                    .push((module.to_owned(), parse_level(level)?)),
+                None => filter.default = parse_level(directive)?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+// Where log events are written.
+enum Sink {
+    Stderr,
+    File(Mutex<File>),
+}
+
+struct Logger {
+    filter: Filter,
+    sink: Sink,
+}
+
+// Nothing is logged until init is called.
+static LOGGER: RwLock<Option<Logger>> = RwLock::new(None);
+
+// The most verbose level any module logs, so that disabled events are cheap to skip.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+// Starts logging events that pass the filter, to the given file or else stderr.
+pub fn init(filter: Filter, path: Option<&str>) -> Result<()> {
+    let sink = match path {
+        Some(path) => Sink::File(Mutex::new(
+            File::create(path).with_context(|| format!("unable to create log file {}", path))?,
+        )),
+        None => Sink::Stderr,
+    };
+    let max = filter.max_overall().map_or(0, |level| level as u8);
+    *LOGGER.write().expect("logger lock") = Some(Logger { filter, sink });
+    MAX_LEVEL.store(max, Ordering::Relaxed);
+    Ok(())
+}
+
+// Whether an event at this level from this module would be logged.
+pub fn enabled(level: Level, module: &str) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    match LOGGER.read().expect("logger lock").as_ref() {
+        Some(logger) => logger.filter.max_level(module) >= Some(level),
+        None => false,
+    }
+}
+
+// Writes one event. Use the log macros instead, which check the filter first.
+pub fn write(level: Level, module: &str, args: fmt::Arguments) {
+    let logger = LOGGER.read().expect("logger lock");
+    let Some(logger) = logger.as_ref() else {
+        return;
+    };
+    // There's nowhere to report a failure to log, so it's ignored.
+    let _ = match &logger.sink {
+        Sink::Stderr => writeln!(io::stderr().lock(), "{:<5} {}: {}", level, module, args),
+        Sink::File(file) => writeln!(
+            file.lock().expect("log file lock"),
+            "{:<5} {}: {}",
+            level,
+            module,
+            args
+        ),
+    };
+}
+
+// Logs an event at the given level, tagged with the current module path.
+// An event can name a target within the module, as in debug!(target: "parse", ...),
+// which is filtered as if it were a submodule, such as day19::parse.
+#[macro_export]
+macro_rules! log {
+    (target: $target:literal, $level:expr, $($arg:tt)+) => {
+        $crate::log!(@event concat!(module_path!(), "::", $target), $level, $($arg)+)
+    };
+    (@event $module:expr, $level:expr, $($arg:tt)+) => {
+        if $crate::common::log::enabled($level, $module) {
+            $crate::common::log::write($level, $module, format_args!($($arg)+));
+        }
+    };
+    ($level:expr, $($arg:tt)+) => {
+        $crate::log!(@event module_path!(), $level, $($arg)+)
+    };
+}
+
+// Whether events at the given level from the current module would be logged.
+// Useful for skipping work that's only done to be logged, like rendering a grid.
+#[macro_export]
+macro_rules! log_enabled {
+    (target: $target:literal, $level:expr) => {
+        $crate::common::log::enabled($level, concat!(module_path!(), "::", $target))
+    };
+    ($level:expr) => {
+        $crate::common::log::enabled($level, module_path!())
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    (target: $target:literal, $($arg:tt)+) => {
+        $crate::log!(target: $target, $crate::common::log::Level::Error, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log!($crate::common::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    (target: $target:literal, $($arg:tt)+) => {
+        $crate::log!(target: $target, $crate::common::log::Level::Warn, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log!($crate::common::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    (target: $target:literal, $($arg:tt)+) => {
+        $crate::log!(target: $target, $crate::common::log::Level::Info, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log!($crate::common::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    (target: $target:literal, $($arg:tt)+) => {
+        $crate::log!(target: $target, $crate::common::log::Level::Debug, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log!($crate::common::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    (target: $target:literal, $($arg:tt)+) => {
+        $crate::log!(target: $target, $crate::common::log::Level::Trace, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log!($crate::common::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_filters() {
+        let filter: Filter = "info,day19=trace,day19::parse=off".parse().unwrap();
+        assert_eq!(filter.max_level("aoc2023::day01"), Some(Level::Info));
+        assert_eq!(filter.max_level("aoc2023::day19"), Some(Level::Trace));
+        assert_eq!(filter.max_level("aoc2023::day19::parse"), None);
+        // Only whole segments match.
+        assert_eq!(filter.max_level("aoc2023::day191"), Some(Level::Info));
+        assert_eq!(filter.max_overall(), Some(Level::Trace));
+
+        assert!("day19=loud".parse::<Filter>().is_err());
+        assert_eq!("".parse::<Filter>().unwrap().max_overall(), None);
+    }
+}
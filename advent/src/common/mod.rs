@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+use std::io;
+use std::ops::Index;
+use std::str::FromStr;
+use thiserror::Error;
+
+pub mod animate;
+mod cycle;
+pub mod dot;
+mod grid;
+mod interval;
+mod lines;
+mod location;
+pub mod log;
+pub mod output;
+pub mod render;
+mod search;
+mod template;
+pub mod vcd;
+
+pub use cycle::{fast_forward, fast_forward_by_key, Cycle, CycleStrategy, FastForward};
+pub use grid::{Array2D, SubGrid, Topology};
+pub use interval::{Interval, IntervalSet, Region, RegionSet};
+pub use lines::{open_lines, read_sections, Line, Lines, Sections, Token, STDIN_PATH};
+pub use location::{Located, Location};
+pub use search::{
+    astar, bfs, dijkstra, resumable_bfs, resumable_dijkstra, Graph, SearchResult, SearchStats,
+};
+pub use template::{Captures, Template};
+
+#[derive(Error, Debug)]
+pub enum CommonError {
+    #[error("unable to open file {path:?}")]
+    FileNotFound { source: io::Error, path: String },
+
+    #[error("unable to read line {line} of {path:?}")]
+    ReadError {
+        source: io::Error,
+        path: String,
+        line: usize,
+    },
+
+    #[error("{}", location::describe(location.as_ref(), format!("unable to parse {:?}", text)))]
+    ParseError {
+        source: Box<dyn std::error::Error + Send + Sync>,
+        text: String,
+        location: Option<Location>,
+    },
+
+    #[error("{}", location::describe(Some(location), format!("expected {:?}", expected)))]
+    TemplateMismatch {
+        expected: String,
+        location: Location,
+    },
+
+    #[error("{}", location::describe(location.as_ref(), format!("mismatched rows: got {}; expected {}", got, expected)))]
+    MismatchedRowsError {
+        got: usize,
+        expected: usize,
+        location: Option<Location>,
+    },
+
+    #[error("marker {name:?} appears at both {first:?} and {second:?}")]
+    DuplicateMarker {
+        name: String,
+        first: (usize, usize),
+        second: (usize, usize),
+    },
+
+    #[error("marker {name:?} not found")]
+    MissingMarker { name: String },
+
+    #[error(transparent)]
+    InnerError(#[from] Box<dyn std::error::Error + Send + Sync>),
+}
+
+pub fn split_on(s: &str, c: char) -> Option<(&str, &str)> {
+    match s.find(c) {
+        Some(i) => {
+            let (s1, s2) = s.split_at(i);
+            let s2 = &s2[1..];
+            Some((s1, s2))
+        }
+        None => None,
+    }
+}
+
+// An input path with optional parameters after a '?', such as "sample.txt?steps=6".
+// Days whose puzzle uses different numbers for the sample and the real input read them
+// from here, so that answers.txt can record runs of both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputSpec<'a> {
+    pub path: &'a str,
+    params: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> InputSpec<'a> {
+    pub fn parse(spec: &'a str) -> Result<Self, CommonError> {
+        let Some((path, query)) = split_on(spec, '?') else {
+            return Ok(InputSpec {
+                path: spec,
+                params: Vec::new(),
+            });
+        };
+        let mut params = Vec::new();
+        for param in query.split('&') {
+            match split_on(param, '=') {
+                Some(pair) => params.push(pair),
+                None => {
+                    return Err(CommonError::ParseError {
+                        source: "expected name=value".into(),
+                        text: param.to_owned(),
+                        location: None,
+                    })
+                }
+            }
+        }
+        Ok(InputSpec { path, params })
+    }
+
+    // Returns the parameter called `name`, or `default` if the spec doesn't have one.
+    pub fn param<T>(&self, name: &str, default: T) -> Result<T, CommonError>
+    where
+        T: FromStr,
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        match self.params.iter().find(|(n, _)| *n == name) {
+            Some((_, value)) => value.parse().map_err(|e: T::Err| CommonError::ParseError {
+                source: e.into(),
+                text: format!("{}={}", name, value),
+                location: None,
+            }),
+            None => Ok(default),
+        }
+    }
+}
+
+// Reads all of the non-blank lines in a file, trimmed. A path of "-" reads stdin.
+pub fn read_lines(path: &str) -> Result<impl Iterator<Item = Line>, CommonError> {
+    let mut v = Vec::new();
+    for line in open_lines(path)? {
+        let line = line?;
+        if !line.is_blank() {
+            v.push(line.trimmed());
+        }
+    }
+    Ok(v.into_iter())
+}
+
+pub fn read_grid<T, E>(path: &str, f: fn(char) -> Result<T, E>) -> Result<Array2D<T>, CommonError>
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let (grid, _) = read_grid_with_markers(path, &[], |c, _, _| f(c))?;
+    Ok(grid)
+}
+
+// Parses a grid from lines that were already read, such as one section of a file.
+pub fn parse_grid<T, E>(
+    lines: impl IntoIterator<Item = Line>,
+    f: fn(char) -> Result<T, E>,
+) -> Result<Array2D<T>, CommonError>
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let (grid, _) = grid_from_lines(lines.into_iter(), &[], |c, _, _| f(c))?;
+    Ok(grid)
+}
+
+// Named positions found while reading a grid, such as "start" and "end".
+#[derive(Debug, Default)]
+pub struct Markers {
+    positions: HashMap<String, (usize, usize)>,
+    duplicate: Option<CommonError>,
+}
+
+impl Markers {
+    // Records that the marker is at (row, col).
+    // If it was already marked elsewhere, reading the grid will fail.
+    pub fn mark(&mut self, name: &str, row: usize, col: usize) {
+        if let Some(&first) = self.positions.get(name) {
+            if self.duplicate.is_none() {
+                self.duplicate = Some(CommonError::DuplicateMarker {
+                    name: name.to_owned(),
+                    first,
+                    second: (row, col),
+                });
+            }
+            return;
+        }
+        self.positions.insert(name.to_owned(), (row, col));
+    }
+
+    pub fn get(&self, name: &str) -> Option<(usize, usize)> {
+        self.positions.get(name).copied()
+    }
+}
+
+impl Index<&str> for Markers {
+    type Output = (usize, usize);
+
+    fn index(&self, name: &str) -> &(usize, usize) {
+        self.positions
+            .get(name)
+            .unwrap_or_else(|| panic!("no marker named {:?}", name))
+    }
+}
+
+// Reads a grid where the callback also gets each cell's (row, col) and can mark positions.
+// Every name in `required` must be marked exactly once.
+pub fn read_grid_with_markers<T, E, F>(
+    path: &str,
+    required: &[&str],
+    f: F,
+) -> Result<(Array2D<T>, Markers), CommonError>
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    F: FnMut(char, (usize, usize), &mut Markers) -> Result<T, E>,
+{
+    grid_from_lines(read_lines(path)?, required, f)
+}
+
+fn grid_from_lines<T, E, F, I>(
+    lines: I,
+    required: &[&str],
+    mut f: F,
+) -> Result<(Array2D<T>, Markers), CommonError>
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    F: FnMut(char, (usize, usize), &mut Markers) -> Result<T, E>,
+    I: Iterator<Item = Line>,
+{
+    let mut markers = Markers::default();
+    let mut v: Vec<Vec<T>> = Vec::new();
+    for (r, line) in lines.enumerate() {
+        let mut row = Vec::new();
+        for (c, (i, ch)) in line.char_indices().enumerate() {
+            match f(ch, (r, c), &mut markers) {
+                Ok(item) => row.push(item),
+                Err(e) => {
+                    return Err(CommonError::ParseError {
+                        source: e.into(),
+                        text: ch.to_string(),
+                        location: Some(line.locate(&line[i..i + ch.len_utf8()])),
+                    });
+                }
+            }
+            if let Some(e) = markers.duplicate.take() {
+                return Err(e);
+            }
+        }
+        if let Some(first) = v.first() {
+            if row.len() != first.len() {
+                return Err(CommonError::MismatchedRowsError {
+                    got: row.len(),
+                    expected: first.len(),
+                    location: line.location(),
+                });
+            }
+        }
+        v.push(row);
+    }
+    for name in required {
+        if markers.get(name).is_none() {
+            return Err(CommonError::MissingMarker {
+                name: (*name).to_owned(),
+            });
+        }
+    }
+    Ok((Array2D::from_rows(v)?, markers))
+}
+
+// Parses every item in the given iterator using FromStr.
+// Errors point at the bad item if it knows where it came from, such as a Line or Token.
+pub fn parse_all<T, I, S>(items: I) -> Result<Vec<T>, CommonError>
+where
+    T: FromStr,
+    S: Located,
+    I: Iterator<Item = S>,
+    T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    items.map(|item| parse_item(&item, str::parse)).collect()
+}
+
+// Parses one trimmed item with `parse`, for parse_all and Token.
+fn parse_item<T, E, S>(item: &S, parse: impl FnOnce(&str) -> Result<T, E>) -> Result<T, CommonError>
+where
+    S: Located,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let part = item.as_ref().trim();
+    parse(part).map_err(|e| CommonError::ParseError {
+        text: part.to_owned(),
+        location: item.location(),
+        source: e.into(),
+    })
+}
+
+// Helper methods for iterators.
+pub trait StrIterator: Iterator {
+    // Parses every item in the given iterator using FromStr.
+    fn parse_all<S, T>(self) -> Result<Vec<T>, CommonError>
+    where
+        S: Located,
+        Self: Iterator<Item = S> + Sized,
+        T: FromStr,
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        crate::common::parse_all(self)
+    }
+}
+
+impl<T> StrIterator for T where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &[&str]) -> impl Iterator<Item = Line> {
+        let text = text.join("\n");
+        Lines::new(Box::new(std::io::Cursor::new(text)), "grid.txt").map(Result::unwrap)
+    }
+
+    fn read(text: &[&str]) -> Result<(Array2D<char>, Markers), CommonError> {
+        let lines = lines(text);
+        grid_from_lines(lines, &["start", "end"], |c, (r, col), markers| {
+            match c {
+                'S' => markers.mark("start", r, col),
+                'E' => markers.mark("end", r, col),
+                _ => {}
+            }
+            Ok::<char, CommonError>(c)
+        })
+    }
+
+    #[test]
+    fn parses_input_specs() {
+        let spec = InputSpec::parse("data/sample.txt?steps=6&wrap=true").unwrap();
+        assert_eq!(spec.path, "data/sample.txt");
+        assert_eq!(spec.param("steps", 64).unwrap(), 6);
+        assert!(spec.param("wrap", false).unwrap());
+        assert_eq!(spec.param("other", 3).unwrap(), 3);
+        assert!(spec.param::<i32>("wrap", 0).is_err());
+
+        let spec = InputSpec::parse("input.txt").unwrap();
+        assert_eq!(spec.path, "input.txt");
+        assert_eq!(spec.param("steps", 64).unwrap(), 64);
+        assert!(InputSpec::parse("input.txt?steps").is_err());
+    }
+
+    #[test]
+    fn finds_markers() {
+        let (grid, markers) = read(&["S..", ".#E"]).unwrap();
+        assert_eq!(grid.rows(), 2);
+        assert_eq!(markers["start"], (0, 0));
+        assert_eq!(markers.get("end"), Some((1, 2)));
+        assert_eq!(markers.get("other"), None);
+    }
+
+    #[test]
+    fn rejects_bad_markers() {
+        assert!(matches!(
+            read(&["S.S", "..E"]),
+            Err(CommonError::DuplicateMarker {
+                first: (0, 0),
+                second: (0, 2),
+                ..
+            })
+        ));
+        assert!(matches!(
+            read(&["S..", "..."]),
+            Err(CommonError::MissingMarker { name }) if name == "end"
+        ));
+    }
+
+    #[test]
+    fn points_at_bad_cells() {
+        let result = grid_from_lines(lines(&["..", ".x"]), &[], |c, _, _| match c {
+            '.' => Ok(c),
+            _ => Err(format!("invalid char {}", c)),
+        });
+        let e = result.unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "grid.txt:2:2: unable to parse \"x\"\n    .x\n     ^"
+        );
+        assert_eq!(
+            std::error::Error::source(&e).unwrap().to_string(),
+            "invalid char x"
+        );
+
+        let result = grid_from_lines(lines(&["..", "..."]), &[], |c, _, _| {
+            Ok::<char, CommonError>(c)
+        });
+        assert!(matches!(
+            result,
+            Err(CommonError::MismatchedRowsError {
+                got: 3,
+                expected: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn points_at_bad_tokens() {
+        let line = lines(&["1 2 three"]).next().unwrap();
+        let e = line
+            .tokens(line.split_whitespace())
+            .parse_all::<_, i32>()
+            .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "grid.txt:1:5: unable to parse \"three\"\n    1 2 three\n        ^^^^^"
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! What solutions write or show besides their answers, as asked for on the command line.
+
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+// Set once by the runner, before any solution runs.
+static SETTINGS: OnceLock<Settings> = OnceLock::new();
+
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    // The directory to write pictures of grids to.
+    pub render_dir: Option<PathBuf>,
+    // The frame rate to play simulations at in the terminal.
+    pub fps: Option<f64>,
+    // Where to write a Graphviz graph of the input.
+    pub dot: Option<PathBuf>,
+    // Whether to label the graph's edges with how often a simulation used them.
+    pub dot_counts: bool,
+    // Where to write a waveform trace.
+    pub vcd: Option<PathBuf>,
+}
+
+// Makes `settings` return these for the rest of the process. Fails if called twice.
+pub fn init(settings: Settings) -> Result<()> {
+    if let Some(dir) = &settings.render_dir {
+        fs::create_dir_all(dir).with_context(|| format!("unable to create {}", dir.display()))?;
+    }
+    SETTINGS
+        .set(settings)
+        .map_err(|_| anyhow!("output settings are already set"))
+}
+
+// Returns what was asked for, which is nothing if `init` wasn't called.
+pub fn settings() -> &'static Settings {
+    SETTINGS.get_or_init(Settings::default)
+}
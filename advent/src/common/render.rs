@@ -0,0 +1,217 @@
+//! Images and GIFs of grids, written as PNG files.
+
+use super::{output, Array2D};
+use anyhow::{bail, Context, Result};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// Returns where to write the picture called `name`, or None if pictures weren't asked for.
+// Solutions should check this before going to the trouble of rendering anything.
+pub fn output(name: &str) -> Option<PathBuf> {
+    let dir = output::settings().render_dir.as_ref();
+    dir.map(|dir| dir.join(name))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const TRANSPARENT: Color = Color {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 0,
+    };
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b, a: 255 }
+    }
+}
+
+// An RGBA picture, with 4 bytes per pixel in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        let pixels =
+            [background.r, background.g, background.b, background.a].repeat(width * height);
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    // Draws each cell of the grid as a `scale` x `scale` square of the color `color` picks for it.
+    pub fn render<T>(grid: &Array2D<T>, scale: usize, color: impl Fn(&T) -> Color) -> Self {
+        let mut image = Image::new(
+            grid.columns() * scale,
+            grid.rows() * scale,
+            Color::TRANSPARENT,
+        );
+        for (r, c, cell) in grid.enumerate() {
+            let color = color(cell);
+            for y in r * scale..(r + 1) * scale {
+                for x in c * scale..(c + 1) * scale {
+                    image.set(x, y, color);
+                }
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Color {
+        let i = self.offset(x, y);
+        let p = &self.pixels[i..i + 4];
+        Color {
+            r: p[0],
+            g: p[1],
+            b: p[2],
+            a: p[3],
+        }
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Color) {
+        let i = self.offset(x, y);
+        self.pixels[i..i + 4].copy_from_slice(&[color.r, color.g, color.b, color.a]);
+    }
+
+    fn offset(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside {}x{}",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        (y * self.width + x) * 4
+    }
+
+    pub fn write_png(&self, path: &Path) -> Result<()> {
+        let file =
+            File::create(path).with_context(|| format!("unable to create {}", path.display()))?;
+        let mut encoder =
+            png::Encoder::new(BufWriter::new(file), self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+// Writes an animated GIF a frame at a time, such as one frame per step of a simulation.
+// Every frame has to be the same size.
+pub struct GifWriter {
+    encoder: gif::Encoder<BufWriter<File>>,
+    width: u16,
+    height: u16,
+    // In hundredths of a second, which is all GIF can do.
+    delay: u16,
+}
+
+impl GifWriter {
+    pub fn create(path: &Path, width: usize, height: usize, delay: Duration) -> Result<Self> {
+        let (Ok(w), Ok(h)) = (u16::try_from(width), u16::try_from(height)) else {
+            bail!("{}x{} is too big for a GIF", width, height);
+        };
+        let file =
+            File::create(path).with_context(|| format!("unable to create {}", path.display()))?;
+        let mut encoder = gif::Encoder::new(BufWriter::new(file), w, h, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        Ok(GifWriter {
+            encoder,
+            width: w,
+            height: h,
+            delay: (delay.as_millis() / 10).min(u16::MAX as u128) as u16,
+        })
+    }
+
+    pub fn add_frame(&mut self, image: &Image) -> Result<()> {
+        if (image.width, image.height) != (self.width as usize, self.height as usize) {
+            bail!(
+                "frame is {}x{}, but the GIF is {}x{}",
+                image.width,
+                image.height,
+                self.width,
+                self.height
+            );
+        }
+        let mut pixels = image.pixels.clone();
+        // Speed 10 is gif's default trade-off between palette quality and encoding time.
+        let mut frame = gif::Frame::from_rgba_speed(self.width, self.height, &mut pixels, 10);
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    fn checkerboard() -> Image {
+        let grid = Array2D::from_rows(vec![vec![true, false], vec![false, true]]).unwrap();
+        Image::render(&grid, 3, |&on| if on { Color::WHITE } else { Color::BLACK })
+    }
+
+    #[test]
+    fn renders_cells_as_squares() {
+        let image = checkerboard();
+        assert_eq!((image.width(), image.height()), (6, 6));
+        assert_eq!(image.get(2, 2), Color::WHITE);
+        assert_eq!(image.get(3, 2), Color::BLACK);
+        assert_eq!(image.get(5, 5), Color::WHITE);
+    }
+
+    #[test]
+    fn writes_files() {
+        let dir = env::temp_dir().join(format!("advent-render-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let image = checkerboard();
+
+        let png_path = dir.join("grid.png");
+        image.write_png(&png_path).unwrap();
+        assert!(fs::read(&png_path).unwrap().starts_with(b"\x89PNG"));
+
+        let gif_path = dir.join("grid.gif");
+        let mut gif = GifWriter::create(&gif_path, 6, 6, Duration::from_millis(100)).unwrap();
+        gif.add_frame(&image).unwrap();
+        gif.add_frame(&image).unwrap();
+        assert!(gif.add_frame(&Image::new(2, 2, Color::BLACK)).is_err());
+        drop(gif);
+        assert!(fs::read(&gif_path).unwrap().starts_with(b"GIF89a"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
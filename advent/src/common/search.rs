@@ -0,0 +1,402 @@
+use crate::checkpoint;
+use priority_queue::DoublePriorityQueue;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::hash::Hash;
+
+// A graph to search, described by how to get from each node to its neighbors.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    // Returns every node reachable in one step from `node`, along with the cost of that step.
+    // Costs must not be negative.
+    fn successors(&self, node: &Self::Node) -> Vec<(Self::Node, i64)>;
+
+    fn is_goal(&self, node: &Self::Node) -> bool;
+
+    // A lower bound on the cost from `node` to the nearest goal. Only used by astar.
+    // It must never overestimate, or astar may return a path that isn't the cheapest.
+    fn heuristic(&self, _node: &Self::Node) -> i64 {
+        0
+    }
+}
+
+// How much work a search did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchStats {
+    // The number of distinct nodes that were ever added to the frontier.
+    pub visited: usize,
+    // The number of nodes whose successors were generated.
+    pub expanded: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResult<N> {
+    pub cost: i64,
+    // Every node from the start to the goal, inclusive.
+    pub path: Vec<N>,
+    pub stats: SearchStats,
+}
+
+fn reconstruct<N: Clone + Eq + Hash>(prev: &HashMap<N, N>, goal: N) -> Vec<N> {
+    let mut path = vec![goal];
+    while let Some(n) = prev.get(path.last().expect("path is not empty")) {
+        path.push(n.clone());
+    }
+    path.reverse();
+    path
+}
+
+// The nodes waiting to be expanded, in whatever order the search keeps them.
+trait Frontier<N> {
+    fn nodes(&self) -> Vec<N>;
+}
+
+impl<N: Clone> Frontier<N> for VecDeque<N> {
+    fn nodes(&self) -> Vec<N> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<N: Clone + Hash + Eq> Frontier<N> for DoublePriorityQueue<N, i64> {
+    fn nodes(&self) -> Vec<N> {
+        self.iter().map(|(n, _)| n.clone()).collect()
+    }
+}
+
+// A search in progress.
+struct Search<N, Q> {
+    dist: HashMap<N, i64>,
+    prev: HashMap<N, N>,
+    q: Q,
+    stats: SearchStats,
+}
+
+// A search in progress, in a form that can be saved to a checkpoint.
+// The frontier's priorities aren't saved, since they can be worked out again from `dist`.
+#[derive(Serialize, Deserialize)]
+struct Snapshot<N> {
+    dist: Vec<(N, i64)>,
+    prev: Vec<(N, N)>,
+    frontier: Vec<N>,
+    stats: SearchStats,
+}
+
+impl<N: Clone + Eq + Hash> Snapshot<N> {
+    fn new<Q: Frontier<N>>(search: &Search<N, Q>) -> Self {
+        Snapshot {
+            dist: search.dist.iter().map(|(n, &d)| (n.clone(), d)).collect(),
+            prev: search
+                .prev
+                .iter()
+                .map(|(n, p)| (n.clone(), p.clone()))
+                .collect(),
+            frontier: search.q.nodes(),
+            stats: search.stats,
+        }
+    }
+
+    // Adds every start that hasn't been seen to the frontier.
+    fn start(starts: impl IntoIterator<Item = N>) -> Self {
+        let mut dist = HashMap::new();
+        let mut frontier = Vec::new();
+        for start in starts {
+            if dist.insert(start.clone(), 0).is_none() {
+                frontier.push(start);
+            }
+        }
+        Snapshot {
+            dist: dist.into_iter().collect(),
+            prev: Vec::new(),
+            stats: SearchStats {
+                visited: frontier.len(),
+                expanded: 0,
+            },
+            frontier,
+        }
+    }
+
+    // Rebuilds the search, ordering the frontier with `queue`, which is given each node's distance.
+    fn resume<Q>(self, queue: impl FnOnce(Vec<N>, &HashMap<N, i64>) -> Q) -> Search<N, Q> {
+        let dist = self.dist.into_iter().collect();
+        Search {
+            q: queue(self.frontier, &dist),
+            dist,
+            prev: self.prev.into_iter().collect(),
+            stats: self.stats,
+        }
+    }
+}
+
+fn infallible<T>(result: Result<T, Infallible>) -> T {
+    match result {
+        Ok(t) => t,
+        Err(never) => match never {},
+    }
+}
+
+// Runs a search from `snapshot`, calling `step` before each node is expanded.
+// If `step` fails, the search stops with its error.
+fn bfs_from<G: Graph, E>(
+    graph: &G,
+    snapshot: Snapshot<G::Node>,
+    mut step: impl FnMut(&Search<G::Node, VecDeque<G::Node>>) -> Result<(), E>,
+) -> Result<Option<SearchResult<G::Node>>, E> {
+    let mut search = snapshot.resume(|frontier, _| VecDeque::from(frontier));
+
+    loop {
+        step(&search)?;
+        let Some(current) = search.q.pop_front() else {
+            return Ok(None);
+        };
+        let d = search.dist[&current];
+        if graph.is_goal(&current) {
+            return Ok(Some(SearchResult {
+                cost: d,
+                path: reconstruct(&search.prev, current),
+                stats: search.stats,
+            }));
+        }
+        search.stats.expanded += 1;
+        for (next, _) in graph.successors(&current) {
+            if search.dist.contains_key(&next) {
+                continue;
+            }
+            search.stats.visited += 1;
+            search.dist.insert(next.clone(), d + 1);
+            search.prev.insert(next.clone(), current.clone());
+            search.q.push_back(next);
+        }
+    }
+}
+
+fn best_first_from<G: Graph, E>(
+    graph: &G,
+    snapshot: Snapshot<G::Node>,
+    use_heuristic: bool,
+    mut step: impl FnMut(&Search<G::Node, DoublePriorityQueue<G::Node, i64>>) -> Result<(), E>,
+) -> Result<Option<SearchResult<G::Node>>, E> {
+    let estimate = |node: &G::Node| {
+        if use_heuristic {
+            graph.heuristic(node)
+        } else {
+            0
+        }
+    };
+
+    let mut search = snapshot.resume(|frontier, dist| {
+        frontier
+            .into_iter()
+            .map(|node| {
+                let priority = dist[&node] + estimate(&node);
+                (node, priority)
+            })
+            .collect::<DoublePriorityQueue<_, _>>()
+    });
+
+    loop {
+        step(&search)?;
+        let Some((current, _)) = search.q.pop_min() else {
+            return Ok(None);
+        };
+        let d = search.dist[&current];
+        if graph.is_goal(&current) {
+            return Ok(Some(SearchResult {
+                cost: d,
+                path: reconstruct(&search.prev, current),
+                stats: search.stats,
+            }));
+        }
+        search.stats.expanded += 1;
+        for (next, cost) in graph.successors(&current) {
+            let d2 = d + cost;
+            let best = match search.dist.get(&next) {
+                Some(&d0) => d2 < d0,
+                None => {
+                    search.stats.visited += 1;
+                    true
+                }
+            };
+            if best {
+                let h = estimate(&next);
+                search.dist.insert(next.clone(), d2);
+                search.prev.insert(next.clone(), current.clone());
+                search.q.push_decrease(next, d2 + h);
+            }
+        }
+    }
+}
+
+// Finds the path with the fewest steps from any of the starts to a goal, ignoring step costs.
+// The cost of the result is the number of steps.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+) -> Option<SearchResult<G::Node>> {
+    infallible(bfs_from(graph, Snapshot::start(starts), |_| Ok(())))
+}
+
+// Finds the cheapest path from any of the starts to a goal.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+) -> Option<SearchResult<G::Node>> {
+    infallible(best_first_from(
+        graph,
+        Snapshot::start(starts),
+        false,
+        |_| Ok(()),
+    ))
+}
+
+// Like dijkstra, but guided by the graph's heuristic, so it usually expands fewer nodes.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+) -> Option<SearchResult<G::Node>> {
+    infallible(best_first_from(
+        graph,
+        Snapshot::start(starts),
+        true,
+        |_| Ok(()),
+    ))
+}
+
+// Picks up the search saved under `key` if the run is resuming, or starts a new one.
+fn resume_or_start<N: DeserializeOwned + Clone + Eq + Hash>(
+    key: &str,
+    starts: impl IntoIterator<Item = N>,
+) -> anyhow::Result<Snapshot<N>> {
+    Ok(checkpoint::resume(key)?.unwrap_or_else(|| Snapshot::start(starts)))
+}
+
+// Like bfs, but saves a checkpoint under `key` if it's interrupted, and continues from one when resuming.
+pub fn resumable_bfs<G>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    key: &str,
+) -> anyhow::Result<Option<SearchResult<G::Node>>>
+where
+    G: Graph,
+    G::Node: Serialize + DeserializeOwned,
+{
+    let snapshot = resume_or_start(key, starts)?;
+    bfs_from(graph, snapshot, |search| {
+        checkpoint::check(key, || Snapshot::new(search))
+    })
+}
+
+// Like dijkstra, but saves a checkpoint under `key` if it's interrupted, and continues from one when resuming.
+pub fn resumable_dijkstra<G>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    key: &str,
+) -> anyhow::Result<Option<SearchResult<G::Node>>>
+where
+    G: Graph,
+    G::Node: Serialize + DeserializeOwned,
+{
+    let snapshot = resume_or_start(key, starts)?;
+    best_first_from(graph, snapshot, false, |search| {
+        checkpoint::check(key, || Snapshot::new(search))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{Array2D, Topology};
+
+    // Moving onto a cell costs its value. Walls are 0 and can't be entered.
+    struct Maze {
+        grid: Array2D<i64>,
+    }
+
+    impl Graph for Maze {
+        type Node = (usize, usize);
+
+        fn successors(&self, node: &Self::Node) -> Vec<(Self::Node, i64)> {
+            self.grid
+                .neighbors4(node.0, node.1, Topology::Bounded)
+                .filter(|(_, _, &cost)| cost > 0)
+                .map(|(r, c, &cost)| ((r, c), cost))
+                .collect()
+        }
+
+        fn is_goal(&self, node: &Self::Node) -> bool {
+            *node == (self.grid.rows() - 1, self.grid.columns() - 1)
+        }
+
+        fn heuristic(&self, node: &Self::Node) -> i64 {
+            ((self.grid.rows() - 1 - node.0) + (self.grid.columns() - 1 - node.1)) as i64
+        }
+    }
+
+    fn maze() -> Maze {
+        let grid = Array2D::from_rows(vec![
+            vec![1, 1, 1, 1],
+            vec![9, 0, 0, 1],
+            vec![1, 1, 1, 1],
+            vec![1, 0, 0, 1],
+        ])
+        .unwrap();
+        Maze { grid }
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        let maze = maze();
+        let d = dijkstra(&maze, [(0, 0)]).unwrap();
+        let a = astar(&maze, [(0, 0)]).unwrap();
+        assert_eq!(d.cost, 6);
+        assert_eq!(a.cost, 6);
+        assert_eq!(d.path.first(), Some(&(0, 0)));
+        assert_eq!(d.path.last(), Some(&(3, 3)));
+        assert_eq!(d.path.len(), 7);
+        assert!(a.stats.expanded <= d.stats.expanded);
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let result = bfs(&maze(), [(0, 0)]).unwrap();
+        assert_eq!(result.cost, 6);
+        assert_eq!(result.path.len(), 7);
+        assert!(result.stats.visited >= result.stats.expanded);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let mut maze = maze();
+        maze.grid[(2, 3)] = 0;
+        maze.grid[(3, 2)] = 0;
+        assert!(dijkstra(&maze, [(0, 0)]).is_none());
+        assert!(bfs(&maze, [(0, 0)]).is_none());
+    }
+
+    #[test]
+    fn resumed_search_finishes_the_same() {
+        let maze = maze();
+        let full = dijkstra(&maze, [(0, 0)]).unwrap();
+
+        let mut steps = 0;
+        let stopped = best_first_from(&maze, Snapshot::start([(0, 0)]), false, |search| {
+            steps += 1;
+            if steps == 4 {
+                return Err(Snapshot::new(search));
+            }
+            Ok(())
+        });
+        let Err(snapshot) = stopped else {
+            panic!("search wasn't interrupted");
+        };
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let snapshot = serde_json::from_str(&json).unwrap();
+        let resumed = infallible(best_first_from(&maze, snapshot, false, |_| Ok(()))).unwrap();
+
+        assert_eq!(resumed.cost, full.cost);
+        assert_eq!(resumed.path, full.path);
+        assert_eq!(resumed.stats, full.stats);
+    }
+}
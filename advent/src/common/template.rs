@@ -0,0 +1,243 @@
+use super::{CommonError, Located, Location};
+use regex::Regex;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+enum Piece {
+    Literal(String),
+    // A named field, and the separator between items if it's a list.
+    Field { name: String, sep: Option<String> },
+}
+
+// A pattern for pulling fields out of a line, such as
+// "Valve {name} has flow rate={rate}; tunnel{_} lead{_} to valve{_} {list:, }".
+// Each field matches as little as it can before the literal text after it.
+// A field named _ is matched but ignored, and {name:sep} is a list separated by sep.
+#[derive(Debug, Clone)]
+pub struct Template {
+    pieces: Vec<Piece>,
+}
+
+impl Template {
+    // Panics if the pattern is malformed, since patterns are written into the code.
+    pub fn new(pattern: &str) -> Self {
+        let field = Regex::new(r"\{(\w+)(?::([^}]+))?\}").expect("valid regex");
+        let mut pieces = Vec::new();
+        let mut last = 0;
+        for m in field.captures_iter(pattern) {
+            let whole = m.get(0).expect("group 0 always matches");
+            if whole.start() > last {
+                pieces.push(Piece::Literal(pattern[last..whole.start()].to_owned()));
+            } else if matches!(pieces.last(), Some(Piece::Field { .. })) {
+                panic!("fields must be separated by text in {:?}", pattern);
+            }
+            pieces.push(Piece::Field {
+                name: m[1].to_owned(),
+                sep: m.get(2).map(|sep| sep.as_str().to_owned()),
+            });
+            last = whole.end();
+        }
+        if last < pattern.len() {
+            pieces.push(Piece::Literal(pattern[last..].to_owned()));
+        }
+        Template { pieces }
+    }
+
+    // Matches the whole line against the template.
+    // If the line is a Line or Token, errors include where it is in the file.
+    pub fn captures<'a, L>(&self, line: &'a L) -> Result<Captures<'a>, CommonError>
+    where
+        L: Located + ?Sized,
+    {
+        let origin = line_location(line.as_ref(), line.location());
+        let line: &'a str = line.as_ref();
+        let mismatch = |expected: &str, pos: usize| CommonError::TemplateMismatch {
+            expected: expected.to_owned(),
+            location: origin.narrow(line, &line[pos..pos]),
+        };
+
+        let mut fields = Vec::new();
+        let mut pos = 0;
+        for (i, piece) in self.pieces.iter().enumerate() {
+            match piece {
+                Piece::Literal(text) => {
+                    if !line[pos..].starts_with(text.as_str()) {
+                        return Err(mismatch(text, pos));
+                    }
+                    pos += text.len();
+                }
+                Piece::Field { name, sep } => {
+                    let end = match self.pieces.get(i + 1) {
+                        Some(Piece::Literal(next)) => match line[pos..].find(next.as_str()) {
+                            Some(n) => pos + n,
+                            None => {
+                                return Err(mismatch(next, divergence(&line[pos..], next) + pos))
+                            }
+                        },
+                        _ => line.len(),
+                    };
+                    if name != "_" {
+                        fields.push(Field {
+                            name: name.clone(),
+                            sep: sep.clone(),
+                            value: &line[pos..end],
+                        });
+                    }
+                    pos = end;
+                }
+            }
+        }
+        if pos < line.len() {
+            return Err(mismatch("end of line", pos));
+        }
+        Ok(Captures {
+            fields,
+            line,
+            origin,
+        })
+    }
+}
+
+// Finds where `rest` stops matching a literal that it doesn't contain, by looking for the
+// longest start of the literal that it does contain. If there isn't one, the field that
+// should have come before the literal is to blame, so this points at its start.
+fn divergence(rest: &str, literal: &str) -> usize {
+    literal
+        .char_indices()
+        .rev()
+        .filter(|&(i, _)| i > 0)
+        .map(|(i, _)| &literal[..i])
+        .find_map(|prefix| rest.find(prefix).map(|n| n + prefix.len()))
+        .unwrap_or(0)
+}
+
+fn line_location(line: &str, location: Option<Location>) -> Location {
+    location.unwrap_or_else(|| Location::of(line, line))
+}
+
+#[derive(Debug)]
+struct Field<'a> {
+    name: String,
+    sep: Option<String>,
+    value: &'a str,
+}
+
+// The fields matched by a Template.
+#[derive(Debug)]
+pub struct Captures<'a> {
+    fields: Vec<Field<'a>>,
+    line: &'a str,
+    // Where the whole line is.
+    origin: Location,
+}
+
+impl<'a> Captures<'a> {
+    fn parse<T>(&self, s: &str) -> Result<T, CommonError>
+    where
+        T: FromStr,
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        s.parse().map_err(|e: T::Err| CommonError::ParseError {
+            source: e.into(),
+            text: s.to_owned(),
+            location: Some(self.origin.narrow(self.line, s)),
+        })
+    }
+
+    fn field(&self, name: &str) -> &Field<'a> {
+        self.fields
+            .iter()
+            .find(|f| f.name == name)
+            .unwrap_or_else(|| panic!("template has no field named {:?}", name))
+    }
+
+    // Returns the text matched by a field. Panics if the template has no such field.
+    pub fn str(&self, name: &str) -> &'a str {
+        self.field(name).value
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T, CommonError>
+    where
+        T: FromStr,
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        self.parse(self.str(name))
+    }
+
+    // Parses each item of a list field. An empty field is an empty list.
+    pub fn list<T>(&self, name: &str) -> Result<Vec<T>, CommonError>
+    where
+        T: FromStr,
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let field = self.field(name);
+        let sep = field
+            .sep
+            .as_deref()
+            .unwrap_or_else(|| panic!("field {:?} is not a list", name));
+        if field.value.is_empty() {
+            return Ok(Vec::new());
+        }
+        field.value.split(sep).map(|s| self.parse(s)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALVE: &str =
+        "Valve {name} has flow rate={rate}; tunnel{_} lead{_} to valve{_} {list:, }";
+
+    #[test]
+    fn parses_fields() {
+        let t = Template::new(VALVE);
+        let c = t
+            .captures("Valve AA has flow rate=0; tunnels lead to valves DD, II, BB")
+            .unwrap();
+        assert_eq!(c.str("name"), "AA");
+        assert_eq!(c.get::<i32>("rate").unwrap(), 0);
+        assert_eq!(c.list::<String>("list").unwrap(), vec!["DD", "II", "BB"]);
+
+        let c = t
+            .captures("Valve HH has flow rate=22; tunnel leads to valve GG")
+            .unwrap();
+        assert_eq!(c.get::<i32>("rate").unwrap(), 22);
+        assert_eq!(c.list::<String>("list").unwrap(), vec!["GG"]);
+    }
+
+    #[test]
+    fn reports_column() {
+        let t = Template::new("{start} = ({left}, {right})");
+        match t.captures("AAA = [BBB, CCC)") {
+            Err(CommonError::TemplateMismatch { expected, location }) => {
+                assert_eq!(expected, " = (");
+                assert_eq!(location.column, 7);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        match t.captures("AAA") {
+            Err(CommonError::TemplateMismatch { location, .. }) => assert_eq!(location.column, 1),
+            other => panic!("unexpected {:?}", other),
+        }
+        match t.captures("AAA = (BBB, CCC) extra") {
+            Err(CommonError::TemplateMismatch { location, .. }) => assert_eq!(location.column, 17),
+            other => panic!("unexpected {:?}", other),
+        }
+        match Template::new("Game {n}: {rest}").captures("Gme 1: x") {
+            Err(CommonError::TemplateMismatch { location, .. }) => assert_eq!(location.column, 1),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reports_bad_values() {
+        let t = Template::new("x={x}");
+        let c = t.captures("x=abc").unwrap();
+        let e = c.get::<i32>("x").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "column 3: unable to parse \"abc\"\n    x=abc\n      ^^^"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Waveform traces of one-bit signals, for circuit-like puzzles.
+
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+// Writes a Value Change Dump of one-bit wires, which waveform viewers such as GTKWave can open.
+// Every wire starts low at time 0, and changes have to be written in time order.
+pub struct VcdWriter<W: Write> {
+    out: W,
+    ids: HashMap<String, String>,
+    values: HashMap<String, bool>,
+    time: u64,
+}
+
+impl VcdWriter<BufWriter<File>> {
+    pub fn create(path: &Path, scope: &str, wires: &[&str]) -> Result<Self> {
+        let file =
+            File::create(path).with_context(|| format!("unable to create {}", path.display()))?;
+        VcdWriter::new(BufWriter::new(file), scope, wires)
+    }
+}
+
+impl<W: Write> VcdWriter<W> {
+    pub fn new(mut out: W, scope: &str, wires: &[&str]) -> Result<Self> {
+        let mut ids = HashMap::new();
+        writeln!(out, "$timescale 1ns $end")?;
+        writeln!(out, "$scope module {} $end", scope)?;
+        for (i, &wire) in wires.iter().enumerate() {
+            if wire.is_empty() || wire.contains(char::is_whitespace) {
+                bail!("invalid wire name {:?}", wire);
+            }
+            let id = identifier(i);
+            writeln!(out, "$var wire 1 {} {} $end", id, wire)?;
+            if ids.insert(wire.to_owned(), id).is_some() {
+                bail!("duplicate wire {:?}", wire);
+            }
+        }
+        writeln!(out, "$upscope $end")?;
+        writeln!(out, "$enddefinitions $end")?;
+        writeln!(out, "#0")?;
+        writeln!(out, "$dumpvars")?;
+        for wire in wires {
+            writeln!(out, "0{}", ids[*wire])?;
+        }
+        writeln!(out, "$end")?;
+        let values = wires.iter().map(|&wire| (wire.to_owned(), false)).collect();
+        Ok(VcdWriter {
+            out,
+            ids,
+            values,
+            time: 0,
+        })
+    }
+
+    // Records `wire` as `high` from `time` on. Nothing is written if it's already at that level.
+    pub fn change(&mut self, time: u64, wire: &str, high: bool) -> Result<()> {
+        let Some(id) = self.ids.get(wire) else {
+            bail!("unknown wire {:?}", wire);
+        };
+        if time < self.time {
+            bail!("time {} is before {}", time, self.time);
+        }
+        let value = self.values.get_mut(wire).expect("every wire has a value");
+        if *value == high {
+            return Ok(());
+        }
+        *value = high;
+        if time != self.time {
+            writeln!(self.out, "#{}", time)?;
+            self.time = time;
+        }
+        writeln!(self.out, "{}{}", if high { '1' } else { '0' }, id)?;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W> {
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+// VCD identifiers are short strings of printable ASCII, from '!' to '~'.
+fn identifier(mut i: usize) -> String {
+    let mut id = String::new();
+    loop {
+        id.push((b'!' + (i % 94) as u8) as char);
+        i /= 94;
+        if i == 0 {
+            return id;
+        }
+        i -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_changes() {
+        let mut vcd = VcdWriter::new(Vec::new(), "day20", &["a", "inv"]).unwrap();
+        vcd.change(3, "a", true).unwrap();
+        vcd.change(3, "inv", true).unwrap();
+        vcd.change(5, "inv", true).unwrap();
+        vcd.change(7, "a", false).unwrap();
+        assert!(vcd.change(6, "a", true).is_err());
+        assert!(vcd.change(8, "b", true).is_err());
+        let text = String::from_utf8(vcd.finish().unwrap()).unwrap();
+        assert!(text.contains("$var wire 1 \" inv $end\n"), "{}", text);
+        assert!(
+            text.ends_with("$dumpvars\n0!\n0\"\n$end\n#3\n1!\n1\"\n#7\n0!\n"),
+            "{}",
+            text
+        );
+        assert_eq!(identifier(93), "~");
+        assert_eq!(identifier(94), "!!");
+    }
+}
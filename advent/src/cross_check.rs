@@ -0,0 +1,118 @@
+use crate::solution::{Answer, Part, Solver};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+// How one strategy did on a part.
+#[derive(Debug)]
+pub struct Run {
+    pub strategy: &'static str,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+impl Display for Run {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<16} {:>12.3?}  ", self.strategy, self.elapsed)?;
+        match &self.answer {
+            Ok(answer) => write!(f, "{}", answer),
+            Err(err) => write!(f, "error: {:#}", err),
+        }
+    }
+}
+
+// Solves `part` with every strategy the solution has, one after another.
+pub fn cross_check(solver: &dyn Solver, input: &dyn Any, part: Part) -> Vec<Run> {
+    solver
+        .strategy_names(part)
+        .into_iter()
+        .map(|strategy| {
+            let start = Instant::now();
+            let answer = solver.solve_with(input, part, strategy);
+            Run {
+                strategy,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+// Returns the answer every strategy gave, or an error naming what each one got if they differ.
+// A strategy that failed counts as a disagreement.
+pub fn agreed(runs: &[Run]) -> Result<Answer> {
+    let answers: Vec<&Answer> = runs.iter().filter_map(|r| r.answer.as_ref().ok()).collect();
+    let all_ok = answers.len() == runs.len();
+    match answers.first() {
+        Some(&first) if all_ok && answers.iter().all(|&a| a == first) => Ok(first.clone()),
+        _ => Err(anyhow!(
+            "strategies disagree: {}",
+            runs.iter()
+                .map(|r| match &r.answer {
+                    Ok(answer) => format!("{} = {}", r.strategy, answer),
+                    Err(err) => format!("{} failed ({:#})", r.strategy, err),
+                })
+                .join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Solution, Strategy};
+    use anyhow::bail;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(&self, _path: &str) -> Result<Self::Input> {
+            Ok(vec![1, 2, 3])
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Answer> {
+            Ok(input.iter().sum::<i64>().into())
+        }
+
+        fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+            Ok(6.into())
+        }
+
+        fn strategies(&self, part: Part) -> Vec<Strategy<Self::Input>> {
+            match part {
+                Part::One => vec![Strategy {
+                    name: "loop",
+                    solve: |input| {
+                        let mut total = 0;
+                        for n in input.iter() {
+                            total += n;
+                        }
+                        Ok(total.into())
+                    },
+                }],
+                Part::Two => vec![Strategy {
+                    name: "broken",
+                    solve: |_| bail!("not implemented"),
+                }],
+            }
+        }
+    }
+
+    #[test]
+    fn compares_strategies() {
+        let input = Solver::parse(&Sum, "").unwrap();
+
+        let runs = cross_check(&Sum, input.as_ref(), Part::One);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(agreed(&runs).unwrap(), Answer::Int(6));
+
+        let runs = cross_check(&Sum, input.as_ref(), Part::Two);
+        let err = agreed(&runs).unwrap_err().to_string();
+        assert!(err.contains("default = 6"), "{}", err);
+        assert!(err.contains("broken failed"), "{}", err);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::common::read_lines;
+use crate::info;
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Sent with every request, so the site knows who to contact about the traffic.
+const USER_AGENT: &str = "github.com/bklimt/advent";
+
+// Settings for downloading inputs, read from a file of "key = value" lines, such as
+//   session = 53616c746564...
+//   base_url = http://localhost:8080
+//   min_interval = 5
+// Lines starting with # are comments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    // The value of the session cookie from a logged in browser.
+    pub session: String,
+    pub base_url: String,
+    // The fewest seconds allowed between requests.
+    pub min_interval: f64,
+    // Where the time of the last request is kept, so the limit holds across runs.
+    pub timestamp_path: PathBuf,
+}
+
+// ~/.config/advent/config, unless ADVENT_CONFIG is set.
+pub fn default_config_path() -> Result<PathBuf> {
+    if let Some(path) = env::var_os("ADVENT_CONFIG") {
+        return Ok(path.into());
+    }
+    let home = env::var_os("HOME").context("neither ADVENT_CONFIG nor HOME is set")?;
+    Ok(Path::new(&home).join(".config/advent/config"))
+}
+
+impl Config {
+    pub fn read(path: &Path) -> Result<Self> {
+        let path_str = path.to_string_lossy();
+        let mut session = None;
+        let mut base_url = DEFAULT_BASE_URL.to_owned();
+        let mut min_interval = 5.0;
+        for line in read_lines(&path_str)? {
+            if line.starts_with('#') {
+                continue;
+            }
+            let location = format!("{}:{}", line.path, line.number);
+            let Some((key, value)) = line.split_once('=') else {
+                bail!("{}: expected \"<key> = <value>\"", location);
+            };
+            let value = value.trim();
+            match key.trim() {
+                "session" => session = Some(value.to_owned()),
+                "base_url" => base_url = value.trim_end_matches('/').to_owned(),
+                "min_interval" => {
+                    min_interval = value
+                        .parse()
+                        .with_context(|| format!("{}: invalid min_interval", location))?
+                }
+                key => bail!("{}: unknown setting {:?}", location, key),
+            }
+        }
+        Ok(Config {
+            session: session.with_context(|| format!("no session set in {}", path_str))?,
+            base_url,
+            min_interval,
+            timestamp_path: path.with_file_name("last-fetch"),
+        })
+    }
+
+    pub fn input_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+}
+
+// Waits until at least min_interval has passed since the last request, then records this one.
+fn wait_for_turn(config: &Config) -> Result<()> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    let last = fs::read_to_string(&config.timestamp_path)
+        .ok()
+        .and_then(|s| s.trim().parse::<f64>().ok())
+        .map(Duration::from_secs_f64);
+    if let Some(last) = last {
+        let next = last + Duration::from_secs_f64(config.min_interval);
+        if next > now {
+            let wait = next - now;
+            info!("waiting {:.1?} before the next request", wait);
+            thread::sleep(wait);
+        }
+    }
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    fs::write(&config.timestamp_path, now.as_secs_f64().to_string()).with_context(|| {
+        format!(
+            "unable to write {}",
+            config.timestamp_path.to_string_lossy()
+        )
+    })
+}
+
+// The result of fetch_input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    // The file was already there, so nothing was requested.
+    Cached,
+}
+
+// Downloads a day's input to `path`, unless it already exists.
+// An empty file, like the one advent new leaves, counts as missing.
+pub fn fetch_input(config: &Config, year: u32, day: u32, path: &Path) -> Result<Fetched> {
+    if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+    let url = config.input_url(year, day);
+    wait_for_turn(config)?;
+    info!("fetching {}", url);
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={}", config.session))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .with_context(|| format!("unable to fetch {}", url))?;
+    let text = response
+        .into_string()
+        .with_context(|| format!("unable to read response from {}", url))?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("unable to create {}", dir.to_string_lossy()))?;
+    }
+    // Write to a temporary file first, so a failure never leaves a partial input behind.
+    let partial = path.with_extension("partial");
+    fs::write(&partial, text)
+        .with_context(|| format!("unable to write {}", partial.to_string_lossy()))?;
+    fs::rename(&partial, path)
+        .with_context(|| format!("unable to write {}", path.to_string_lossy()))?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    // Serves `body` to each of `count` requests, and sends back the request lines.
+    fn stub_server(body: &'static str, count: usize) -> (String, mpsc::Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming().take(count) {
+                let mut stream = stream.unwrap();
+                let request: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                tx.send(request).unwrap();
+            }
+        });
+        (url, rx)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("advent-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn reads_config() {
+        let dir = temp_dir("config");
+        let path = dir.join("config");
+        fs::write(&path, "# token\nsession = abc123\nbase_url = http://x/\n").unwrap();
+        let config = Config::read(&path).unwrap();
+        assert_eq!(config.session, "abc123");
+        assert_eq!(config.input_url(2023, 22), "http://x/2023/day/22/input");
+        assert_eq!(config.timestamp_path, dir.join("last-fetch"));
+
+        fs::write(&path, "base_url = http://x/\n").unwrap();
+        assert!(Config::read(&path).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let dir = temp_dir("wait");
+        let config = Config {
+            session: String::new(),
+            base_url: String::new(),
+            min_interval: 0.2,
+            timestamp_path: dir.join("last-fetch"),
+        };
+        wait_for_turn(&config).unwrap();
+        let start = std::time::Instant::now();
+        wait_for_turn(&config).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(150));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn downloads_once() {
+        let dir = temp_dir("download");
+        let (base_url, requests) = stub_server("1 2 3\n", 1);
+        let config = Config {
+            session: "abc123".to_owned(),
+            base_url,
+            min_interval: 0.0,
+            timestamp_path: dir.join("last-fetch"),
+        };
+        let input = dir.join("22/input.txt");
+
+        assert_eq!(
+            fetch_input(&config, 2023, 22, &input).unwrap(),
+            Fetched::Downloaded
+        );
+        assert_eq!(fs::read_to_string(&input).unwrap(), "1 2 3\n");
+        let request = requests.recv().unwrap();
+        assert_eq!(request[0], "GET /2023/day/22/input HTTP/1.1");
+        assert!(request.iter().any(|h| h == "Cookie: session=abc123"));
+
+        // The server only answers once, so this would fail if it made a request.
+        assert_eq!(
+            fetch_input(&config, 2023, 22, &input).unwrap(),
+            Fetched::Cached
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,10 @@
+pub mod batch;
+pub mod bench;
+pub mod checkpoint;
+pub mod common;
+pub mod cross_check;
+pub mod fetch;
+pub mod scaffold;
+pub mod solution;
+pub mod verify;
+pub mod viewer;
@@ -0,0 +1,297 @@
+use crate::verify::{data_dir, ANSWERS_FILE};
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// The starting point for a new day's solution. {{DAY}} is replaced with the two digit day.
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+
+const ANSWERS_TEMPLATE: &str = "\
+# <file> <part> <answer>, checked by advent verify.
+# sample.txt 1 42
+";
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("unable to read {}", path.to_string_lossy()))
+}
+
+fn write(path: &Path, text: &str) -> Result<()> {
+    fs::write(path, text).with_context(|| format!("unable to write {}", path.to_string_lossy()))
+}
+
+// Inserts `line` among the lines that `matches` picks out, keeping them sorted by `key`.
+// If none of the lines match, it goes before the first line that `fallback` picks out,
+// or else at the end.
+fn insert_sorted(
+    text: &str,
+    line: &str,
+    matches: impl Fn(&str) -> Option<u32>,
+    key: u32,
+    fallback: impl Fn(&str) -> bool,
+) -> Result<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let mut index = None;
+    for (i, existing) in lines.iter().enumerate() {
+        match matches(existing) {
+            Some(k) if k == key => bail!("{:?} is already there", existing.trim()),
+            Some(k) if k < key => index = Some(i + 1),
+            Some(_) => {
+                index.get_or_insert(i);
+                break;
+            }
+            None => {}
+        }
+    }
+    let index = index
+        .or_else(|| lines.iter().position(|l| fallback(l)))
+        .unwrap_or(lines.len());
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+// Parses the number out of a line like "mod day07;" or "pub mod year2023;".
+fn numbered(line: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    line.trim()
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+// Parses the day out of a line like "    registry.add(2023, 7, day07::Day07);".
+fn registered_day(line: &str, year: u32) -> Option<u32> {
+    let rest = line
+        .trim()
+        .strip_prefix(&format!("registry.add({}, ", year))?;
+    rest.split(',').next()?.parse().ok()
+}
+
+// The manifest of a new year's crate. {{YEAR}} is replaced with the year.
+const CARGO_TEMPLATE: &str = "\
+[package]
+name = \"aoc{{YEAR}}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[dependencies]
+advent = { path = \"../advent\" }
+anyhow = \"1.0\"
+";
+
+// Parses the year out of a line like "aoc2023 = { path = \"../2023\" }".
+fn dependency_year(line: &str) -> Option<u32> {
+    let (name, _) = line.trim().strip_prefix("aoc")?.split_once(' ')?;
+    name.parse().ok()
+}
+
+// Adds a crate for a new year with its first day, and hooks it into the workspace and runner.
+fn add_year(root: &Path, year: u32, day: u32) -> Result<()> {
+    let dir = root.join(year.to_string());
+    fs::create_dir_all(dir.join("src"))?;
+    write(
+        &dir.join("Cargo.toml"),
+        &CARGO_TEMPLATE.replace("{{YEAR}}", &year.to_string()),
+    )?;
+    write(
+        &dir.join("src/lib.rs"),
+        &format!(
+            "mod day{day:02};\n\n\
+             use advent::solution::Registry;\n\n\
+             pub fn register(registry: &mut Registry) {{\n\
+             \x20   registry.add({year}, {day}, day{day:02}::Day{day:02});\n\
+             }}\n"
+        ),
+    )?;
+
+    let workspace = root.join("Cargo.toml");
+    let text = insert_sorted(
+        &read(&workspace)?,
+        &format!("    \"{}\",", year),
+        |l| numbered(l, "\"", "\","),
+        year,
+        |l| l == "]",
+    )?;
+    write(&workspace, &text)?;
+
+    let runner = root.join("runner/Cargo.toml");
+    let text = insert_sorted(
+        &read(&runner)?,
+        &format!("aoc{} = {{ path = \"../{}\" }}", year, year),
+        dependency_year,
+        year,
+        |_| false,
+    )?;
+    write(&runner, &text)?;
+
+    let main = root.join("runner/src/main.rs");
+    let text = insert_sorted(
+        &read(&main)?,
+        &format!("    aoc{}::register(&mut registry);", year),
+        |l| numbered(l, "aoc", "::register(&mut registry);"),
+        year,
+        |l| l.trim() == "registry",
+    )?;
+    write(&main, &text)
+}
+
+// What new_day created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaffold {
+    pub module: PathBuf,
+    pub data: PathBuf,
+}
+
+// Creates YYYY/src/dayDD.rs from the template, registers it, and creates YYYY/data/DD with
+// empty sample and input files and a commented answers manifest. A new year gets its own
+// crate in the workspace. Nothing is overwritten, so it fails if the day already exists.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Scaffold> {
+    if !(1..=25).contains(&day) {
+        bail!("invalid day: {}", day);
+    }
+    let src = root.join(year.to_string()).join("src");
+    let module = src.join(format!("day{:02}.rs", day));
+    if module.exists() {
+        bail!("{} already exists", module.to_string_lossy());
+    }
+    let lib = src.join("lib.rs");
+    if lib.exists() {
+        let text = insert_sorted(
+            &read(&lib)?,
+            &format!("mod day{:02};", day),
+            |l| numbered(l, "mod day", ";"),
+            day,
+            |_| true,
+        )
+        .and_then(|text| {
+            insert_sorted(
+                &text,
+                &format!(
+                    "    registry.add({}, {}, day{:02}::Day{:02});",
+                    year, day, day, day
+                ),
+                |l| registered_day(l, year),
+                day,
+                |l| l == "}",
+            )
+        })
+        .with_context(|| {
+            format!(
+                "unable to register day {} in {}",
+                day,
+                lib.to_string_lossy()
+            )
+        })?;
+        write(&lib, &text)?;
+    } else {
+        add_year(root, year, day)?;
+    }
+    write(
+        &module,
+        &DAY_TEMPLATE.replace("{{DAY}}", &format!("{:02}", day)),
+    )?;
+
+    let data = data_dir(root, year, day);
+    fs::create_dir_all(&data)?;
+    for (name, text) in [
+        ("sample.txt", ""),
+        ("input.txt", ""),
+        (ANSWERS_FILE, ANSWERS_TEMPLATE),
+    ] {
+        let path = data.join(name);
+        if !path.exists() {
+            write(&path, text)?;
+        }
+    }
+    Ok(Scaffold { module, data })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("advent-new-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    const LIB_RS: &str = "\
+mod day01;
+mod day03;
+
+use advent::solution::Registry;
+
+pub fn register(registry: &mut Registry) {
+    registry.add(2023, 1, day01::Day01);
+    registry.add(2023, 3, day03::Day03);
+}
+";
+
+    #[test]
+    fn registers_in_order() {
+        let root = temp_dir("order");
+        fs::create_dir_all(root.join("2023/src")).unwrap();
+        fs::write(root.join("2023/src/lib.rs"), LIB_RS).unwrap();
+
+        new_day(&root, 2023, 2).unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("2023/src/lib.rs")).unwrap(),
+            LIB_RS
+                .replace("mod day01;\n", "mod day01;\nmod day02;\n")
+                .replace(
+                    "day01::Day01);\n",
+                    "day01::Day01);\n    registry.add(2023, 2, day02::Day02);\n"
+                )
+        );
+        let module = fs::read_to_string(root.join("2023/src/day02.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day02 {"));
+        assert!(root.join("2023/data/02/input.txt").exists());
+        assert!(root.join("2023/data/02/answers.txt").exists());
+
+        assert!(new_day(&root, 2023, 2).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn adds_new_year() {
+        let root = temp_dir("year");
+        fs::create_dir_all(root.join("runner/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"advent\",\n    \"2023\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("runner/Cargo.toml"),
+            "[dependencies]\naoc2023 = { path = \"../2023\" }\nclap = \"4\"\n\n[features]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("runner/src/main.rs"),
+            "    let mut registry = Registry::new();\n    aoc2023::register(&mut registry);\n    registry\n",
+        )
+        .unwrap();
+
+        new_day(&root, 2024, 1).unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"advent\",\n    \"2023\",\n    \"2024\",\n]\n"
+        );
+        assert!(fs::read_to_string(root.join("runner/Cargo.toml"))
+            .unwrap()
+            .contains("aoc2023 = { path = \"../2023\" }\naoc2024 = { path = \"../2024\" }\nclap"));
+        assert!(fs::read_to_string(root.join("runner/src/main.rs"))
+            .unwrap()
+            .contains("aoc2024::register(&mut registry);\n    registry\n"));
+        assert!(fs::read_to_string(root.join("2024/Cargo.toml"))
+            .unwrap()
+            .contains("name = \"aoc2024\""));
+        assert_eq!(
+            fs::read_to_string(root.join("2024/src/lib.rs")).unwrap(),
+            "mod day01;\n\nuse advent::solution::Registry;\n\npub fn register(registry: &mut Registry) {\n    registry.add(2024, 1, day01::Day01);\n}\n"
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,222 @@
+use anyhow::{bail, Result};
+use serde::Serialize;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    // Wide enough for any i64 or u64 answer.
+    Int(i128),
+    Str(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(i128::from(n))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        // usize is at most 64 bits on every platform this runs on.
+        Answer::from(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_owned())
+    }
+}
+
+// Which half of a puzzle to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub fn from_number(n: u32) -> Result<Self> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => bail!("invalid part: {}", n),
+        }
+    }
+
+    pub fn number(&self) -> u32 {
+        *self as u32
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+// The name --cross-check gives to a solution's own part1 and part2.
+pub const DEFAULT_STRATEGY: &str = "default";
+
+// Another way of solving a part, which --cross-check compares against the default.
+pub struct Strategy<I> {
+    pub name: &'static str,
+    pub solve: fn(&I) -> Result<Answer>,
+}
+
+// A solution to a single day's puzzle.
+// The input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input: 'static;
+
+    fn parse(&self, path: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+
+    // Alternatives to part1 or part2, which should always give the same answer.
+    fn strategies(&self, _part: Part) -> Vec<Strategy<Self::Input>> {
+        Vec::new()
+    }
+}
+
+thread_local! {
+    static COUNTERS: RefCell<BTreeMap<&'static str, u64>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+// Records a solver-specific number, such as how many nodes a search expanded,
+// to be reported alongside the answer of the part being solved.
+pub fn record_counter(name: &'static str, value: u64) {
+    COUNTERS.with(|counters| counters.borrow_mut().insert(name, value));
+}
+
+// Returns the counters recorded since the last call on this thread.
+pub fn take_counters() -> BTreeMap<&'static str, u64> {
+    COUNTERS.with(|counters| counters.take())
+}
+
+// A type-erased Solution, so that every day can live in the same registry.
+// Solvers are shared between threads when running several inputs at once.
+pub trait Solver: Send + Sync {
+    fn parse(&self, path: &str) -> Result<Box<dyn Any>>;
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<Answer>;
+
+    // The names of every way to solve `part`, starting with DEFAULT_STRATEGY.
+    fn strategy_names(&self, part: Part) -> Vec<&'static str>;
+    fn solve_with(&self, input: &dyn Any, part: Part, strategy: &str) -> Result<Answer>;
+}
+
+impl<S: Solution + Send + Sync> Solver for S {
+    fn parse(&self, path: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(Solution::parse(self, path)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<Answer> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input should come from the same solution");
+        // Don't report counters left over from parsing or another part.
+        take_counters();
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    fn strategy_names(&self, part: Part) -> Vec<&'static str> {
+        let others = self.strategies(part).into_iter().map(|s| s.name);
+        [DEFAULT_STRATEGY].into_iter().chain(others).collect()
+    }
+
+    fn solve_with(&self, input: &dyn Any, part: Part, strategy: &str) -> Result<Answer> {
+        if strategy == DEFAULT_STRATEGY {
+            return Solver::solve(self, input, part);
+        }
+        let Some(found) = self
+            .strategies(part)
+            .into_iter()
+            .find(|s| s.name == strategy)
+        else {
+            bail!("part {} has no strategy named {:?}", part, strategy);
+        };
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input should come from the same solution");
+        take_counters();
+        (found.solve)(input)
+    }
+}
+
+// Every known solution, keyed by (year, day).
+// Each year's crate has a register function that adds its days.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<(u32, u32), Box<dyn Solver>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add<S: Solver + 'static>(&mut self, year: u32, day: u32, solver: S) {
+        if self.days.insert((year, day), Box::new(solver)).is_some() {
+            panic!("day {} of {} registered twice", day, year);
+        }
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Option<&dyn Solver> {
+        self.days.get(&(year, day)).map(|s| s.as_ref())
+    }
+
+    // Iterates over (year, day, solver) in order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, u32, &dyn Solver)> {
+        self.days
+            .iter()
+            .map(|(&(year, day), s)| (year, day, s.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_large_answers() {
+        let answer = Answer::from(u64::MAX);
+        assert_eq!(answer.to_string(), "18446744073709551615");
+        assert_eq!(
+            serde_json::to_string(&answer).unwrap(),
+            "18446744073709551615"
+        );
+        assert_eq!(Answer::from(-3i64), Answer::from(-3i32));
+        assert_eq!(Answer::from(7usize), Answer::from(7u32));
+    }
+}
@@ -0,0 +1,193 @@
+use crate::batch::input_files;
+use crate::common::{read_lines, InputSpec};
+use crate::solution::{Part, Solver};
+use anyhow::{anyhow, bail, Context, Result};
+use itertools::Itertools;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+// The name of the manifest of expected answers in each day's data directory.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+// Where a day's inputs and answers live: YYYY/data/DD under the workspace root.
+pub fn data_dir(root: &Path, year: u32, day: u32) -> PathBuf {
+    root.join(year.to_string())
+        .join("data")
+        .join(format!("{:02}", day))
+}
+
+// One line of an answers manifest, such as "sample1.txt 2 11687500".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub file: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+impl Expected {
+    fn from_line(line: &str) -> Result<Self> {
+        let mut fields = line.splitn(3, char::is_whitespace);
+        let (Some(file), Some(part), Some(answer)) = (fields.next(), fields.next(), fields.next())
+        else {
+            bail!("expected \"<file> <part> <answer>\", got {:?}", line);
+        };
+        let part = part
+            .parse()
+            .with_context(|| format!("invalid part in {:?}", line))?;
+        Ok(Expected {
+            file: file.to_owned(),
+            part: Part::from_number(part)?,
+            answer: answer.trim().to_owned(),
+        })
+    }
+}
+
+// Reads an answers manifest. Lines starting with # are comments.
+pub fn read_answers(path: &str) -> Result<Vec<Expected>> {
+    let mut v = Vec::new();
+    for line in read_lines(path)? {
+        if line.starts_with('#') {
+            continue;
+        }
+        v.push(Expected::from_line(&line).with_context(|| format!("in {}", path))?);
+    }
+    Ok(v)
+}
+
+#[derive(Debug)]
+pub enum Status {
+    Pass,
+    Mismatch { actual: String },
+    Error(anyhow::Error),
+}
+
+// The result of checking one expected answer.
+#[derive(Debug)]
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    pub expected: Expected,
+    pub status: Status,
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        matches!(self.status, Status::Pass)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = if self.passed() { "ok  " } else { "FAIL" };
+        write!(
+            f,
+            "{} {} day {:02} part {} {}",
+            label, self.year, self.day, self.expected.part, self.expected.file
+        )?;
+        match &self.status {
+            Status::Pass => Ok(()),
+            Status::Mismatch { actual } => write!(
+                f,
+                "\n    - expected: {}\n    +   actual: {}",
+                self.expected.answer, actual
+            ),
+            Status::Error(e) => write!(
+                f,
+                "\n    - expected: {}\n    +    error: {:#}",
+                self.expected.answer, e
+            ),
+        }
+    }
+}
+
+// Checks every answer recorded in a day's manifest.
+// Each input file is only parsed once, even if both parts are recorded.
+pub fn verify_day(year: u32, day: u32, solver: &dyn Solver, dir: &Path) -> Result<Vec<Outcome>> {
+    let manifest = dir.join(ANSWERS_FILE);
+    let expected = read_answers(&manifest.to_string_lossy())?;
+
+    let files: Vec<&str> = expected.iter().map(|e| e.file.as_str()).unique().collect();
+
+    let mut outcomes = Vec::new();
+    for file in files {
+        let path = dir.join(file);
+        let parsed = solver.parse(&path.to_string_lossy());
+        for e in expected.iter().filter(|e| e.file == file) {
+            let status = match &parsed {
+                Ok(input) => match solver.solve(input.as_ref(), e.part) {
+                    Ok(answer) if answer.to_string() == e.answer => Status::Pass,
+                    Ok(answer) => Status::Mismatch {
+                        actual: answer.to_string(),
+                    },
+                    Err(err) => Status::Error(err),
+                },
+                Err(err) => Status::Error(anyhow!("{:#}", err)),
+            };
+            outcomes.push(Outcome {
+                year,
+                day,
+                expected: e.clone(),
+                status,
+            });
+        }
+    }
+    Ok(outcomes)
+}
+
+// Returns the input files in a day's data directory that the manifest has no answers for,
+// so that verify can point out what it isn't checking.
+pub fn unchecked_inputs(dir: &Path) -> Result<Vec<String>> {
+    let manifest = dir.join(ANSWERS_FILE);
+    let expected = read_answers(&manifest.to_string_lossy())?;
+    let mut checked = Vec::new();
+    for e in expected.iter() {
+        checked.push(InputSpec::parse(&e.file)?.path);
+    }
+    Ok(input_files(dir)?
+        .iter()
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .filter(|name| !checked.contains(&name.as_str()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_manifest_line() {
+        let e = Expected::from_line("sample1.txt 2 11687500").unwrap();
+        assert_eq!(e.file, "sample1.txt");
+        assert_eq!(e.part, Part::Two);
+        assert_eq!(e.answer, "11687500");
+    }
+
+    #[test]
+    fn keeps_spaces_in_answer() {
+        let e = Expected::from_line("input.txt 1 A B C").unwrap();
+        assert_eq!(e.answer, "A B C");
+    }
+
+    #[test]
+    fn finds_unchecked_inputs() {
+        let dir = std::env::temp_dir().join(format!("advent-verify-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in ["input.txt", "sample.txt", "scratch.txt"] {
+            std::fs::write(dir.join(file), "1\n").unwrap();
+        }
+        std::fs::write(
+            dir.join(ANSWERS_FILE),
+            "input.txt 1 1\nsample.txt?steps=6 2 1\n",
+        )
+        .unwrap();
+        assert_eq!(unchecked_inputs(&dir).unwrap(), vec!["scratch.txt"]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_bad_lines() {
+        assert!(Expected::from_line("input.txt 1").is_err());
+        assert!(Expected::from_line("input.txt 3 42").is_err());
+    }
+}
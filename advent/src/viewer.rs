@@ -0,0 +1,441 @@
+use crate::common::render::{Color, Image};
+use crate::common::Array2D;
+use anyhow::Result;
+use std::fmt::Debug;
+
+// Something the viewer can draw as a grid of colored cells.
+pub trait Renderable {
+    // The number of rows and columns.
+    fn size(&self) -> (usize, usize);
+
+    fn color(&self, row: usize, col: usize) -> Color;
+
+    // What to show in the status line when the cursor is over a cell.
+    fn describe(&self, row: usize, col: usize) -> String;
+
+    // Draws every cell as one pixel.
+    fn image(&self) -> Image {
+        let (rows, cols) = self.size();
+        let mut image = Image::new(cols, rows, Color::TRANSPARENT);
+        for row in 0..rows {
+            for col in 0..cols {
+                image.set(col, row, self.color(row, col));
+            }
+        }
+        image
+    }
+}
+
+// A Renderable that changes over time, which the viewer can run or step through.
+pub trait Simulation: Renderable {
+    // Advances one step. Returns false once there's nothing left to do.
+    fn step(&mut self) -> Result<bool>;
+}
+
+// Shows an Array2D, with each cell colored by `color` and described by its Debug output.
+pub struct GridView<'a, T, F> {
+    grid: &'a Array2D<T>,
+    color: F,
+}
+
+impl<'a, T, F> GridView<'a, T, F>
+where
+    T: Debug,
+    F: Fn(&T) -> Color,
+{
+    pub fn new(grid: &'a Array2D<T>, color: F) -> Self {
+        GridView { grid, color }
+    }
+}
+
+impl<T, F> Renderable for GridView<'_, T, F>
+where
+    T: Debug,
+    F: Fn(&T) -> Color,
+{
+    fn size(&self) -> (usize, usize) {
+        (self.grid.rows(), self.grid.columns())
+    }
+
+    fn color(&self, row: usize, col: usize) -> Color {
+        (self.color)(&self.grid[(row, col)])
+    }
+
+    fn describe(&self, row: usize, col: usize) -> String {
+        format!("{:?}", self.grid[(row, col)])
+    }
+}
+
+// The smallest and largest a cell can be drawn, in pixels.
+const MIN_SCALE: f64 = 0.05;
+const MAX_SCALE: f64 = 64.0;
+
+// Which part of the grid is on screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    // The grid position, in cells, at the top left corner of the window.
+    pub x: f64,
+    pub y: f64,
+    // How many pixels wide each cell is.
+    pub scale: f64,
+}
+
+impl Viewport {
+    // Fits a whole grid in a window, centered.
+    pub fn fit(rows: usize, cols: usize, width: u32, height: u32) -> Self {
+        let scale = (width as f64 / cols.max(1) as f64)
+            .min(height as f64 / rows.max(1) as f64)
+            .clamp(MIN_SCALE, MAX_SCALE);
+        Viewport {
+            x: (cols as f64 - width as f64 / scale) / 2.0,
+            y: (rows as f64 - height as f64 / scale) / 2.0,
+            scale,
+        }
+    }
+
+    // Moves the grid along with a mouse drag of (dx, dy) pixels.
+    pub fn pan(&mut self, dx: i32, dy: i32) {
+        self.x -= dx as f64 / self.scale;
+        self.y -= dy as f64 / self.scale;
+    }
+
+    // Zooms in by `factor`, or out if it's less than 1, keeping the point under pixel (px, py) still.
+    pub fn zoom(&mut self, factor: f64, px: i32, py: i32) {
+        let (gx, gy) = self.grid_point(px, py);
+        self.scale = (self.scale * factor).clamp(MIN_SCALE, MAX_SCALE);
+        self.x = gx - px as f64 / self.scale;
+        self.y = gy - py as f64 / self.scale;
+    }
+
+    fn grid_point(&self, px: i32, py: i32) -> (f64, f64) {
+        (
+            self.x + px as f64 / self.scale,
+            self.y + py as f64 / self.scale,
+        )
+    }
+
+    // The (row, col) of the cell under pixel (px, py), if it's in a grid of the given size.
+    pub fn cell_at(&self, px: i32, py: i32, rows: usize, cols: usize) -> Option<(usize, usize)> {
+        let (gx, gy) = self.grid_point(px, py);
+        if gx < 0.0 || gy < 0.0 || gx >= cols as f64 || gy >= rows as f64 {
+            return None;
+        }
+        Some((gy as usize, gx as usize))
+    }
+
+    // Where the top left corner of the grid is on screen, in pixels.
+    pub fn origin(&self) -> (i32, i32) {
+        (
+            (-self.x * self.scale).round() as i32,
+            (-self.y * self.scale).round() as i32,
+        )
+    }
+}
+
+#[cfg(feature = "sdl")]
+pub use window::Viewer;
+
+#[cfg(feature = "sdl")]
+mod window {
+    use super::{Renderable, Simulation, Viewport};
+    use crate::common::render::{Color, Image};
+    use anyhow::{anyhow, Result};
+    use sdl2::event::Event;
+    use sdl2::keyboard::Keycode;
+    use sdl2::pixels::{Color as SdlColor, PixelFormatEnum};
+    use sdl2::rect::Rect;
+    use std::fmt::Display;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    fn sdl_error(e: impl Display) -> anyhow::Error {
+        anyhow!("sdl: {}", e)
+    }
+
+    // A still grid, as a simulation that never moves.
+    struct Still<'a, R>(&'a R);
+
+    impl<R: Renderable> Renderable for Still<'_, R> {
+        fn size(&self) -> (usize, usize) {
+            self.0.size()
+        }
+
+        fn color(&self, row: usize, col: usize) -> Color {
+            self.0.color(row, col)
+        }
+
+        fn describe(&self, row: usize, col: usize) -> String {
+            self.0.describe(row, col)
+        }
+
+        fn image(&self) -> Image {
+            self.0.image()
+        }
+    }
+
+    impl<R: Renderable> Simulation for Still<'_, R> {
+        fn step(&mut self) -> Result<bool> {
+            Ok(false)
+        }
+    }
+
+    // A window for looking at grids.
+    // Drag to pan, scroll to zoom, space to pause, n to step once, f to fit, and escape or q to close.
+    // Set SDL_VIDEODRIVER=dummy to run it without a display.
+    pub struct Viewer {
+        title: String,
+        width: u32,
+        height: u32,
+        steps_per_second: f64,
+        max_frames: Option<u64>,
+    }
+
+    impl Viewer {
+        pub fn new(title: &str) -> Self {
+            Viewer {
+                title: title.to_owned(),
+                width: 1280,
+                height: 720,
+                steps_per_second: 10.0,
+                max_frames: None,
+            }
+        }
+
+        pub fn size(mut self, width: u32, height: u32) -> Self {
+            self.width = width;
+            self.height = height;
+            self
+        }
+
+        pub fn steps_per_second(mut self, steps: f64) -> Self {
+            self.steps_per_second = steps;
+            self
+        }
+
+        // Closes the window by itself after this many frames, for tests and scripts.
+        pub fn max_frames(mut self, frames: u64) -> Self {
+            self.max_frames = Some(frames);
+            self
+        }
+
+        // Shows a grid until the window is closed.
+        pub fn show(&self, grid: &impl Renderable) -> Result<()> {
+            self.run(&mut Still(grid)).map(|_| ())
+        }
+
+        // Runs a simulation until the window is closed, and returns how many steps it took.
+        pub fn run(&self, sim: &mut impl Simulation) -> Result<u64> {
+            let sdl = sdl2::init().map_err(sdl_error)?;
+            let video = sdl.video().map_err(sdl_error)?;
+            let window = video
+                .window(&self.title, self.width, self.height)
+                .position_centered()
+                .resizable()
+                .build()
+                .map_err(sdl_error)?;
+            let builder = window.into_canvas();
+            // The dummy driver has no accelerated renderer.
+            let builder = if video.current_video_driver() == "dummy" {
+                builder.software()
+            } else {
+                builder.accelerated().present_vsync()
+            };
+            let mut canvas = builder.build().map_err(sdl_error)?;
+            let creator = canvas.texture_creator();
+            let mut events = sdl.event_pump().map_err(sdl_error)?;
+
+            let (rows, cols) = sim.size();
+            let (w, h) = canvas.output_size().map_err(sdl_error)?;
+            let mut view = Viewport::fit(rows, cols, w, h);
+            let mut texture = creator
+                .create_texture_streaming(PixelFormatEnum::RGBA32, cols as u32, rows as u32)
+                .map_err(sdl_error)?;
+            let mut dirty = true;
+            let mut paused = false;
+            let mut done = false;
+            let mut steps = 0;
+            let mut frames = 0;
+            let mut last_step = Instant::now();
+            let step_time = Duration::from_secs_f64(1.0 / self.steps_per_second.max(0.001));
+            let mut status = String::new();
+            let mut mouse = (-1, -1);
+
+            'running: loop {
+                let mut step_once = false;
+                for event in events.poll_iter() {
+                    match event {
+                        Event::Quit { .. }
+                        | Event::KeyDown {
+                            keycode: Some(Keycode::Escape | Keycode::Q),
+                            ..
+                        } => break 'running,
+                        Event::KeyDown {
+                            keycode: Some(Keycode::Space),
+                            ..
+                        } => paused = !paused,
+                        Event::KeyDown {
+                            keycode: Some(Keycode::N),
+                            ..
+                        } => {
+                            paused = true;
+                            step_once = true;
+                        }
+                        Event::KeyDown {
+                            keycode: Some(Keycode::F),
+                            ..
+                        } => {
+                            let (w, h) = canvas.output_size().map_err(sdl_error)?;
+                            view = Viewport::fit(rows, cols, w, h);
+                        }
+                        Event::MouseMotion {
+                            mousestate,
+                            x,
+                            y,
+                            xrel,
+                            yrel,
+                            ..
+                        } => {
+                            mouse = (x, y);
+                            if mousestate.left() {
+                                view.pan(xrel, yrel);
+                            }
+                        }
+                        Event::MouseWheel { y, .. } => view.zoom(1.25f64.powi(y), mouse.0, mouse.1),
+                        _ => {}
+                    }
+                }
+
+                let due = !paused && last_step.elapsed() >= step_time;
+                if !done && (step_once || due) {
+                    last_step = Instant::now();
+                    if sim.step()? {
+                        steps += 1;
+                        dirty = true;
+                    } else {
+                        done = true;
+                    }
+                }
+
+                if dirty {
+                    let image = sim.image();
+                    texture
+                        .update(None, image.pixels(), image.width() * 4)
+                        .map_err(sdl_error)?;
+                    dirty = false;
+                }
+
+                canvas.set_draw_color(SdlColor::RGB(40, 40, 40));
+                canvas.clear();
+                let (x, y) = view.origin();
+                let dst = Rect::new(
+                    x,
+                    y,
+                    (cols as f64 * view.scale).ceil().max(1.0) as u32,
+                    (rows as f64 * view.scale).ceil().max(1.0) as u32,
+                );
+                canvas.copy(&texture, None, dst).map_err(sdl_error)?;
+                canvas.present();
+
+                // There's no text rendering without SDL_ttf, so the status line is the title.
+                let mut new_status = format!("{} - step {}", self.title, steps);
+                if let Some((r, c)) = view.cell_at(mouse.0, mouse.1, rows, cols) {
+                    new_status += &format!(" - ({}, {}): {}", r, c, sim.describe(r, c));
+                }
+                if done {
+                    new_status += " [done]";
+                } else if paused {
+                    new_status += " [paused]";
+                }
+                if new_status != status {
+                    canvas
+                        .window_mut()
+                        .set_title(&new_status)
+                        .map_err(sdl_error)?;
+                    status = new_status;
+                }
+
+                frames += 1;
+                if self.max_frames.is_some_and(|max| frames >= max) {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(1000 / 60));
+            }
+            Ok(steps)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::common::render::Color;
+
+        struct Counter {
+            n: usize,
+        }
+
+        impl Renderable for Counter {
+            fn size(&self) -> (usize, usize) {
+                (1, 8)
+            }
+
+            fn color(&self, _row: usize, col: usize) -> Color {
+                if col < self.n {
+                    Color::WHITE
+                } else {
+                    Color::BLACK
+                }
+            }
+
+            fn describe(&self, _row: usize, col: usize) -> String {
+                (col < self.n).to_string()
+            }
+        }
+
+        impl Simulation for Counter {
+            fn step(&mut self) -> Result<bool> {
+                if self.n == 8 {
+                    return Ok(false);
+                }
+                self.n += 1;
+                Ok(true)
+            }
+        }
+
+        #[test]
+        fn runs_headless() {
+            std::env::set_var("SDL_VIDEODRIVER", "dummy");
+            let mut counter = Counter { n: 0 };
+            let steps = Viewer::new("test")
+                .size(64, 64)
+                .steps_per_second(1000.0)
+                .max_frames(30)
+                .run(&mut counter)
+                .unwrap();
+            assert_eq!(steps, 8);
+            assert_eq!(counter.n, 8);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zoom_keeps_point_under_cursor() {
+        let mut view = Viewport::fit(10, 20, 200, 100);
+        assert_eq!(view.scale, 10.0);
+        assert_eq!(view.cell_at(0, 0, 10, 20), Some((0, 0)));
+        assert_eq!(view.cell_at(199, 99, 10, 20), Some((9, 19)));
+
+        let before = view.cell_at(55, 35, 10, 20);
+        view.zoom(4.0, 55, 35);
+        assert_eq!(view.scale, 40.0);
+        assert_eq!(view.cell_at(55, 35, 10, 20), before);
+
+        // Dragging left moves the grid left with the mouse.
+        view.pan(-40, 0);
+        assert_eq!(view.cell_at(15, 35, 10, 20), before);
+        assert_eq!(view.cell_at(-1000, 0, 10, 20), None);
+    }
+}
@@ -0,0 +1,27 @@
+use advent::common::read_lines;
+use advent::debug;
+use advent::solution::{Answer, Solution};
+use anyhow::{bail, Result};
+
+fn read_input(path: &str) -> Result<Vec<String>> {
+    Ok(read_lines(path)?.map(|line| line.text).collect())
+}
+
+pub struct Day{{DAY}};
+
+impl Solution for Day{{DAY}} {
+    type Input = Vec<String>;
+
+    fn parse(&self, path: &str) -> Result<Self::Input> {
+        read_input(path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        debug!("read {} lines", input.len());
+        bail!("part 1 is not solved yet")
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+        bail!("part 2 is not solved yet")
+    }
+}
@@ -0,0 +1,559 @@
+use advent::batch::{self, FileResult};
+use advent::bench;
+use advent::checkpoint::{self, Interrupted};
+use advent::common::log::{self, Filter, Level};
+use advent::common::output;
+use advent::cross_check;
+use advent::fetch::{self, Config, Fetched};
+use advent::scaffold;
+use advent::solution::{take_counters, Answer, Part, Registry};
+use advent::verify::{self, data_dir, ANSWERS_FILE};
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+
+    /// The workspace root. Each year's data is in YYYY/data/DD under it.
+    #[arg(long, global = true, default_value = ".")]
+    root: String,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Runs one day's solution against an input file.
+    Run(RunArgs),
+
+    /// Checks every day against the answers recorded in YYYY/data/DD/answers.txt.
+    Verify {
+        /// Verifies every year if not specified.
+        #[arg(long)]
+        year: Option<u32>,
+
+        /// Verifies every day if not specified.
+        #[arg(short, long)]
+        day: Option<u32>,
+
+        #[command(flatten)]
+        log: LogArgs,
+    },
+
+    /// Downloads a puzzle input to YYYY/data/DD/input.txt, unless it's already there.
+    Fetch {
+        #[arg(long, default_value_t = 2023)]
+        year: u32,
+
+        #[arg(short, long)]
+        day: u32,
+
+        /// Holds the session token. Defaults to $ADVENT_CONFIG or ~/.config/advent/config.
+        #[arg(long)]
+        config: Option<String>,
+
+        /// Overrides the base_url in the config file.
+        #[arg(long)]
+        base_url: Option<String>,
+
+        #[command(flatten)]
+        log: LogArgs,
+    },
+
+    /// Starts a new day: a module from the template, its registration, and data files.
+    New {
+        #[arg(long, default_value_t = 2023)]
+        year: u32,
+
+        #[arg(short, long)]
+        day: u32,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+struct RunArgs {
+    #[arg(long, default_value_t = 2023)]
+    year: u32,
+
+    #[arg(short, long)]
+    day: u32,
+
+    /// Runs both parts if not specified.
+    #[arg(short, long)]
+    part: Option<u32>,
+
+    /// Defaults to YYYY/data/DD/input.txt. Use - to read stdin.
+    /// Some days take parameters after a ?, such as sample.txt?steps=6.
+    #[arg(short, long)]
+    input: Option<String>,
+
+    #[command(flatten)]
+    log: LogArgs,
+
+    /// How to print the answers. json prints one object per line for each part.
+    #[arg(long, value_enum, default_value_t = Format::Text, conflicts_with = "bench")]
+    format: Format,
+
+    #[command(flatten)]
+    bench: BenchArgs,
+
+    /// Where long searches save their state when stopped with Ctrl-C.
+    /// Defaults to target/checkpoint.json under the root.
+    #[arg(long, value_name = "FILE")]
+    checkpoint: Option<String>,
+
+    /// Continues from the checkpoint saved when this run was last stopped.
+    #[arg(long, conflicts_with = "bench")]
+    resume: bool,
+
+    /// Runs every input in YYYY/data/DD at once and prints a table of the results.
+    /// Can't be combined with the options that write pictures, graphs or traces,
+    /// since every input would write to the same place.
+    #[arg(
+        long,
+        conflicts_with_all = ["input", "bench", "resume", "render", "animate", "dot", "vcd"]
+    )]
+    all_inputs: bool,
+
+    /// How many inputs to run at once with --all-inputs. Defaults to the number of CPUs.
+    #[arg(long, value_name = "N", requires = "all_inputs")]
+    threads: Option<usize>,
+
+    #[command(flatten)]
+    output: OutputArgs,
+
+    /// Solves each part with every strategy the solution has, and fails if they disagree.
+    #[arg(long, conflicts_with_all = ["bench", "all_inputs", "resume", "format"])]
+    cross_check: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct LogArgs {
+    /// Which events to log, such as "debug" or "warn,day19=trace". Defaults to warn.
+    #[arg(long, value_name = "FILTER")]
+    log: Option<Filter>,
+
+    /// Short for --log debug.
+    #[arg(long, conflicts_with = "log")]
+    debug: bool,
+
+    /// Writes log events to a file instead of stderr.
+    #[arg(long, value_name = "FILE")]
+    log_file: Option<String>,
+}
+
+impl LogArgs {
+    fn init(self) -> Result<()> {
+        let filter = match self.log {
+            Some(filter) => filter,
+            None if self.debug => Filter::level(Level::Debug),
+            None => Filter::level(Level::Warn),
+        };
+        log::init(filter, self.log_file.as_deref())
+    }
+}
+
+#[derive(clap::Args, Debug)]
+struct OutputArgs {
+    /// Writes pictures of grids to DIR, for the days that draw them.
+    #[arg(long, value_name = "DIR")]
+    render: Option<String>,
+
+    /// Plays simulations in the terminal at FPS frames per second, for the days that have them.
+    #[arg(long, value_name = "FPS", num_args = 0..=1, default_missing_value = "30")]
+    animate: Option<f64>,
+
+    /// Writes a Graphviz graph of the input to FILE, for the days that draw one.
+    #[arg(long, value_name = "FILE", conflicts_with = "bench")]
+    dot: Option<String>,
+
+    /// Labels the edges of the --dot graph with how often a simulation used them.
+    #[arg(long, requires = "dot")]
+    dot_counts: bool,
+
+    /// Writes a waveform trace to FILE in Value Change Dump format, for the days that record one.
+    /// Each part records its own trace, so this needs --part.
+    #[arg(long, value_name = "FILE", requires = "part", conflicts_with = "bench")]
+    vcd: Option<String>,
+}
+
+impl OutputArgs {
+    fn init(self) -> Result<()> {
+        output::init(output::Settings {
+            render_dir: self.render.map(PathBuf::from),
+            fps: self.animate,
+            dot: self.dot.map(PathBuf::from),
+            dot_counts: self.dot_counts,
+            vcd: self.vcd.map(PathBuf::from),
+        })
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+// The result of one part, as printed by --format json.
+#[derive(Serialize, Debug)]
+struct Report {
+    year: u32,
+    day: u32,
+    part: u32,
+    // Only set with --all-inputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    elapsed_ms: f64,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    counters: BTreeMap<&'static str, u64>,
+}
+
+#[derive(clap::Args, Debug)]
+struct BenchArgs {
+    /// Times parsing and each part over N runs, instead of printing the answers.
+    #[arg(long, value_name = "N")]
+    bench: Option<usize>,
+
+    /// Writes the benchmark results to a file, to compare against later.
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    save: Option<String>,
+
+    /// Compares the benchmark against results saved by an earlier run.
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    compare: Option<String>,
+
+    /// The percentage a median can get slower before --compare fails.
+    #[arg(long, default_value_t = 10.0, requires = "compare")]
+    threshold: f64,
+}
+
+fn run(registry: &Registry, root: &Path, args: RunArgs) -> Result<()> {
+    let RunArgs {
+        year,
+        day,
+        part,
+        input,
+        log,
+        format,
+        bench,
+        checkpoint,
+        resume,
+        all_inputs,
+        threads,
+        cross_check: check_strategies,
+        output,
+    } = args;
+    log.init()?;
+    output.init()?;
+    let solver = registry
+        .get(year, day)
+        .with_context(|| format!("no solution for day {} of {}", day, year))?;
+    let parts = match part {
+        Some(n) => vec![Part::from_number(n)?],
+        None => vec![Part::One, Part::Two],
+    };
+    if all_inputs {
+        let threads = threads.unwrap_or_else(batch::default_threads);
+        let results = batch::run_all(solver, &data_dir(root, year, day), &parts, threads)?;
+        return report_all(year, day, &results, format);
+    }
+    let input = input.unwrap_or_else(|| {
+        data_dir(root, year, day)
+            .join("input.txt")
+            .to_string_lossy()
+            .into_owned()
+    });
+    if let Some(runs) = bench.bench {
+        let timings = bench::bench_day(year, day, solver, &input, &parts, runs)?;
+        return report_bench(&timings, &bench);
+    }
+    let run_name = |part: Part| format!("{} day {} part {} ({})", year, day, part, input);
+    let checkpoint_path = match checkpoint {
+        Some(path) => path.into(),
+        None => root.join("target").join("checkpoint.json"),
+    };
+    let saved = if resume {
+        let saved = checkpoint::load(&checkpoint_path)?;
+        if !parts.iter().any(|&part| run_name(part) == saved.run) {
+            bail!(
+                "{} is a checkpoint for {}, not this run",
+                checkpoint_path.to_string_lossy(),
+                saved.run
+            );
+        }
+        Some(saved)
+    } else {
+        None
+    };
+    checkpoint::handle_ctrl_c()?;
+
+    let parsed = solver.parse(&input)?;
+    let mut saved = saved;
+    for part in parts {
+        if check_strategies {
+            let runs = cross_check::cross_check(solver, parsed.as_ref(), part);
+            for run in runs.iter() {
+                println!("part {} {}", part, run);
+            }
+            let answer = cross_check::agreed(&runs).with_context(|| format!("part {}", part))?;
+            println!("part {}: {}", part, answer);
+            continue;
+        }
+        // Only the part that was interrupted gets the saved state.
+        let resume = saved.take_if(|saved| saved.run == run_name(part));
+        checkpoint::begin(&checkpoint_path, &run_name(part), resume);
+        let start = Instant::now();
+        let answer = solver.solve(parsed.as_ref(), part);
+        let elapsed = start.elapsed();
+        checkpoint::end();
+        let answer = answer?;
+        match format {
+            Format::Text => println!("part {}: {}", part, answer),
+            Format::Json => {
+                let report = Report {
+                    year,
+                    day,
+                    part: part.number(),
+                    file: None,
+                    answer: Some(answer),
+                    error: None,
+                    elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+                    counters: take_counters(),
+                };
+                println!("{}", serde_json::to_string(&report)?);
+            }
+        }
+    }
+    Ok(())
+}
+
+// Prints the results of --all-inputs, and fails if any of them don't match answers.txt.
+// Errors on files or parts without a recorded answer are shown, but don't count as failures.
+fn report_all(year: u32, day: u32, results: &[FileResult], format: Format) -> Result<()> {
+    let answer_text = |r: &FileResult| match &r.answer {
+        Ok(answer) => answer.to_string(),
+        Err(err) => format!("{:#}", err),
+    };
+    match format {
+        Format::Text => {
+            let width = results
+                .iter()
+                .map(|r| r.file.len())
+                .chain(["file".len()])
+                .max()
+                .unwrap_or_default();
+            println!(
+                "{:<width$}  part  {:>12}  check  answer",
+                "file",
+                "time",
+                width = width
+            );
+            for r in results.iter() {
+                let check = match r.matches() {
+                    Some(true) => "ok",
+                    Some(false) => "FAIL",
+                    None if r.answer.is_err() => "error",
+                    None => "-",
+                };
+                println!(
+                    "{:<width$}  {:>4}  {:>12.3?}  {:<5}  {}",
+                    r.file,
+                    r.part.number(),
+                    r.elapsed,
+                    check,
+                    answer_text(r),
+                    width = width
+                );
+                if r.matches() == Some(false) {
+                    if let Some(expected) = &r.expected {
+                        println!("{:>width$}  expected: {}", "", expected, width = width);
+                    }
+                }
+            }
+        }
+        Format::Json => {
+            for r in results.iter() {
+                let report = Report {
+                    year,
+                    day,
+                    part: r.part.number(),
+                    file: Some(r.file.clone()),
+                    answer: r.answer.as_ref().ok().cloned(),
+                    error: r.answer.as_ref().err().map(|err| format!("{:#}", err)),
+                    elapsed_ms: r.elapsed.as_secs_f64() * 1000.0,
+                    counters: r.counters.clone(),
+                };
+                println!("{}", serde_json::to_string(&report)?);
+            }
+        }
+    }
+    let failed = results
+        .iter()
+        .filter(|r| r.matches() == Some(false))
+        .count();
+    if failed > 0 {
+        bail!("{} of {} results failed", failed, results.len());
+    }
+    Ok(())
+}
+
+fn report_bench(timings: &[bench::Timing], args: &BenchArgs) -> Result<()> {
+    for timing in timings.iter() {
+        println!("{}", timing);
+    }
+    if let Some(path) = &args.save {
+        bench::write_results(path, timings)?;
+    }
+    if let Some(path) = &args.compare {
+        let baseline = bench::read_results(path)?;
+        let changes = bench::compare(timings, &baseline, args.threshold);
+        if changes.is_empty() {
+            let t = &timings[0];
+            bail!("{} has no results for day {} of {}", path, t.day, t.year);
+        }
+        let mut regressed = 0;
+        for change in changes.iter() {
+            println!("{}", change);
+            if change.regressed {
+                regressed += 1;
+            }
+        }
+        if regressed > 0 {
+            bail!(
+                "{} phases got more than {}% slower than {}",
+                regressed,
+                args.threshold,
+                path
+            );
+        }
+    }
+    Ok(())
+}
+
+fn verify(registry: &Registry, root: &Path, year: Option<u32>, day: Option<u32>) -> Result<()> {
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut unchecked = 0;
+    for (y, d, solver) in registry.iter() {
+        if year.is_some_and(|year| year != y) || day.is_some_and(|day| day != d) {
+            continue;
+        }
+        let dir = data_dir(root, y, d);
+        if !dir.join(ANSWERS_FILE).exists() {
+            println!("skip {} day {:02} (no {})", y, d, ANSWERS_FILE);
+            continue;
+        }
+        for outcome in verify::verify_day(y, d, solver, &dir)? {
+            println!("{}", outcome);
+            if outcome.passed() {
+                passed += 1;
+            } else {
+                failed += 1;
+            }
+        }
+        for file in verify::unchecked_inputs(&dir)? {
+            println!("warn {} day {:02} {} has no recorded answers", y, d, file);
+            unchecked += 1;
+        }
+    }
+    if unchecked > 0 {
+        println!(
+            "{} passed, {} failed, {} inputs not checked",
+            passed, failed, unchecked
+        );
+    } else {
+        println!("{} passed, {} failed", passed, failed);
+    }
+    if failed > 0 {
+        bail!("{} answers did not match", failed);
+    }
+    Ok(())
+}
+
+fn fetch(
+    root: &Path,
+    year: u32,
+    day: u32,
+    config: Option<String>,
+    base_url: Option<String>,
+) -> Result<()> {
+    let config_path = match config {
+        Some(path) => path.into(),
+        None => fetch::default_config_path()?,
+    };
+    let mut config = Config::read(&config_path)?;
+    if let Some(base_url) = base_url {
+        config.base_url = base_url.trim_end_matches('/').to_owned();
+    }
+    let path = data_dir(root, year, day).join("input.txt");
+    match fetch::fetch_input(&config, year, day, &path)? {
+        Fetched::Downloaded => println!("downloaded {}", path.to_string_lossy()),
+        Fetched::Cached => println!("{} already exists", path.to_string_lossy()),
+    }
+    Ok(())
+}
+
+fn new_day(root: &Path, year: u32, day: u32) -> Result<()> {
+    let created = scaffold::new_day(root, year, day)?;
+    println!("created {}", created.module.to_string_lossy());
+    println!("created {}", created.data.to_string_lossy());
+    Ok(())
+}
+
+// Every year's solutions.
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    aoc2022::register(&mut registry);
+    aoc2023::register(&mut registry);
+    registry
+}
+
+fn process(args: Args) -> Result<()> {
+    let registry = registry();
+    let root = Path::new(&args.root);
+    match args.command {
+        Command::Run(args) => run(&registry, root, args),
+        Command::Verify { year, day, log } => {
+            log.init()?;
+            verify(&registry, root, year, day)
+        }
+        Command::Fetch {
+            year,
+            day,
+            config,
+            base_url,
+            log,
+        } => {
+            log.init()?;
+            fetch(root, year, day, config, base_url)
+        }
+        Command::New { year, day } => new_day(root, year, day),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    match process(args) {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(error) => {
+            if let Some(interrupted) = error.downcast_ref::<Interrupted>() {
+                eprintln!("{}", interrupted);
+                eprintln!("run the same command with --resume to continue");
+                return ExitCode::from(130);
+            }
+            eprintln!("error: {:?}", error);
+            ExitCode::FAILURE
+        }
+    }
+}